@@ -118,25 +118,12 @@ fn batch_size_for_n(n: usize) -> BatchSize {
     }
 }
 
-#[inline]
-fn is_hex_byte(b: u8) -> bool {
-    b.is_ascii_hexdigit()
-}
-
-fn validate_hex_ascii(hex: &[u8]) -> bool {
-    if (hex.len() & 1) != 0 {
-        return false;
-    }
-    // Tight scalar loop, no allocations, no dst writes.
-    hex.iter().copied().all(is_hex_byte)
-}
-
 #[inline]
 fn debug_assert_valid_hex(hex: &[u8]) {
     // Bench data must be valid hex. If this ever triggers, the generator is wrong or the buffer
     // is being reused after in-place decode.
     debug_assert!(
-        validate_hex_ascii(hex),
+        fast_hex_lite::validate(hex).is_ok(),
         "bench input is not valid ASCII hex"
     );
 }
@@ -179,6 +166,20 @@ fn bench_decode(c: &mut Criterion) {
                     })
                 });
 
+                // fast-hex-lite single-pass (partial writes allowed on error)
+                group.bench_with_input(
+                    BenchmarkId::new("fast-hex-lite-fused", n),
+                    &hex,
+                    |b, hex_in| {
+                        b.iter(|| {
+                            let written =
+                                fast_hex_lite::decode_to_slice_fused(black_box(hex_in), &mut dst)
+                                    .unwrap();
+                            black_box(written);
+                        })
+                    },
+                );
+
                 // hex crate (no per-iter allocation)
                 group.bench_with_input(BenchmarkId::new("hex-crate", n), &hex, |b, hex_in| {
                     b.iter(|| {
@@ -220,10 +221,17 @@ fn bench_validate_only(c: &mut Criterion) {
 
                 group.bench_with_input(BenchmarkId::new("validate", n), &hex, |b, hex_in| {
                     b.iter(|| {
-                        let ok = validate_hex_ascii(black_box(hex_in));
+                        let ok = fast_hex_lite::validate(black_box(hex_in)).is_ok();
                         black_box(ok);
                     })
                 });
+
+                group.bench_with_input(BenchmarkId::new("classify", n), &hex, |b, hex_in| {
+                    b.iter(|| {
+                        let report = fast_hex_lite::classify(black_box(hex_in));
+                        black_box(report);
+                    })
+                });
             }
 
             group.finish();
@@ -299,6 +307,46 @@ fn bench_encode(c: &mut Criterion) {
     }
 }
 
+// ── Separated encode ─────────────────────────────────────────────────────
+
+fn bench_encode_separated(c: &mut Criterion) {
+    for class in [SizeClass::Small, SizeClass::Med, SizeClass::Large] {
+        let mut group = c.benchmark_group(format!(
+            "{}/encode_separated/{}",
+            fast_hex_variant(),
+            class.name()
+        ));
+        class.configure(&mut group);
+
+        for &n in class.sizes() {
+            let src = make_random_bytes(n);
+
+            // Throughput base for encode: **input bytes**.
+            group.throughput(Throughput::Bytes(n as u64));
+
+            // `de:ad:be:ef` (MAC / fingerprint) and `deadbeef 01020304`.
+            for (name, sep, g) in [("group1-colon", b":", 1), ("group4-space", b" ", 4)] {
+                let mut dst = vec![0u8; fast_hex_lite::encoded_len_separated(n, sep.len(), g)];
+                group.bench_with_input(BenchmarkId::new(name, n), &src, |b, s| {
+                    b.iter(|| {
+                        let written = fast_hex_lite::encode_to_slice_separated(
+                            black_box(s),
+                            &mut dst,
+                            sep,
+                            g,
+                            true,
+                        )
+                        .unwrap();
+                        black_box(written);
+                    })
+                });
+            }
+        }
+
+        group.finish();
+    }
+}
+
 // ── decode_in_place ───────────────────────────────────────────────────────
 
 fn bench_decode_in_place(c: &mut Criterion) {
@@ -340,11 +388,47 @@ fn bench_decode_in_place(c: &mut Criterion) {
     }
 }
 
+// ── Batch benchmarks (many 32-byte hashes) ───────────────────────────────
+
+fn bench_decode_batch(c: &mut Criterion) {
+    const ITEMS: usize = 1024;
+
+    let mut group = c.benchmark_group(format!("{}/decode_batch/hash32", fast_hex_variant()));
+    let hex: Vec<Vec<u8>> = make_random_bytes(ITEMS * 32)
+        .chunks(32)
+        .map(bytes_to_hex_lower)
+        .collect();
+    let items: Vec<&[u8]> = hex.iter().map(Vec::as_slice).collect();
+    group.throughput(Throughput::Bytes((ITEMS * 64) as u64));
+
+    group.bench_function("decode_to_array_loop", |b| {
+        let mut out = vec![[0u8; 32]; ITEMS];
+        b.iter(|| {
+            for (src, dst) in black_box(&items).iter().zip(out.iter_mut()) {
+                *dst = fast_hex_lite::decode_to_array::<32>(src).unwrap();
+            }
+            black_box(&out);
+        })
+    });
+
+    group.bench_function("decode_batch", |b| {
+        let mut out = vec![[0u8; 32]; ITEMS];
+        b.iter(|| {
+            fast_hex_lite::decode_batch(black_box(&items), &mut out).unwrap();
+            black_box(&out);
+        })
+    });
+
+    group.finish();
+}
+
 criterion_group!(
     benches,
     bench_decode,
     bench_validate_only,
     bench_encode,
-    bench_decode_in_place
+    bench_encode_separated,
+    bench_decode_in_place,
+    bench_decode_batch
 );
 criterion_main!(benches);
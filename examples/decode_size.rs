@@ -0,0 +1,17 @@
+//! Minimal binary for comparing the decoder's footprint with and without
+//! the `small-tables` feature:
+//!
+//! ```text
+//! cargo build --release --example decode_size
+//! cargo build --release --example decode_size --features small-tables
+//! size target/release/examples/decode_size
+//! ```
+
+fn main() {
+    let hex = std::env::args().nth(1).unwrap_or_else(|| "deadbeef".into());
+    let mut buf = vec![0u8; hex.len() / 2];
+    match fast_hex_lite::decode_to_slice(hex.as_bytes(), &mut buf) {
+        Ok(n) => println!("{:?}", &buf[..n]),
+        Err(e) => eprintln!("{e}"),
+    }
+}
@@ -0,0 +1,188 @@
+//! Kernel introspection and runtime override.
+
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+/// Environment variable that pins the backend at first use (`std` only).
+/// Accepts the names from [`Backend::name`]; unknown or unsupported values
+/// are ignored.
+#[cfg(all(feature = "simd", not(feature = "forbid-unsafe"), feature = "std"))]
+pub(crate) const BACKEND_ENV: &str = "FAST_HEX_LITE_BACKEND";
+
+/// Kernel family used by the decode / validate / encode paths.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Backend {
+    /// Portable scalar code (tables or SWAR).
+    Scalar,
+    /// SSE2, 16 hex chars per iteration (`x86_64` baseline).
+    Sse2,
+    /// NEON, 16 hex chars per iteration (aarch64 baseline).
+    Neon,
+    /// AVX2, 32 hex chars per iteration (`x86_64`, runtime-detected).
+    Avx2,
+    /// AVX-512 BW + VBMI, 64 hex chars per iteration (`x86_64`,
+    /// runtime-detected).
+    Avx512,
+}
+
+impl Backend {
+    /// Lowercase name, as accepted by the `FAST_HEX_LITE_BACKEND` variable.
+    pub const fn name(self) -> &'static str {
+        match self {
+            Backend::Scalar => "scalar",
+            Backend::Sse2 => "sse2",
+            Backend::Neon => "neon",
+            Backend::Avx2 => "avx2",
+            Backend::Avx512 => "avx512",
+        }
+    }
+
+    #[cfg(all(feature = "simd", not(feature = "forbid-unsafe"), feature = "std"))]
+    pub(crate) fn from_name(name: &str) -> Option<Backend> {
+        [
+            Backend::Scalar,
+            Backend::Sse2,
+            Backend::Neon,
+            Backend::Avx2,
+            Backend::Avx512,
+        ]
+        .into_iter()
+        .find(|b| name.trim().eq_ignore_ascii_case(b.name()))
+    }
+}
+
+impl core::fmt::Display for Backend {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// The backend that decode / validate / encode currently dispatch to.
+///
+/// Without the `simd` feature (or with `forbid-unsafe`) this is always
+/// [`Backend::Scalar`]. Otherwise it is the widest kernel the CPU supports,
+/// unless lowered by [`set_backend`] or `FAST_HEX_LITE_BACKEND`.
+///
+/// The fixed sizes of [`decode_to_array`](crate::decode_to_array) and
+/// [`encode_to_array`](crate::encode_to_array) (16, 20, 32, 64 bytes) are
+/// the exception: they always run the baseline SSE2 / NEON kernel, or scalar
+/// code when [`Backend::Scalar`] is pinned, whatever tier is reported here.
+///
+/// # Examples
+/// ```
+/// use fast_hex_lite::{backend, Backend};
+///
+/// let b = backend();
+/// println!("decoding with {b}");
+/// # let _ = b == Backend::Scalar;
+/// ```
+pub fn backend() -> Backend {
+    #[cfg(all(feature = "simd", not(feature = "forbid-unsafe")))]
+    {
+        crate::simd::backend()
+    }
+    #[cfg(any(not(feature = "simd"), feature = "forbid-unsafe"))]
+    {
+        Backend::Scalar
+    }
+}
+
+/// Pin the backend for the whole process, e.g. `set_backend(Backend::Scalar)`
+/// to rule out SIMD while debugging. Results never depend on the backend.
+///
+/// Returns `false` and changes nothing if `backend` is not available in
+/// this build or on this CPU.
+///
+/// `set_backend` takes precedence over the `FAST_HEX_LITE_BACKEND`
+/// variable, whether it is called before or after the first codec call:
+/// the variable is read only when the backend is first detected, which is
+/// skipped once a backend has been pinned, and detection never replaces a
+/// pin made concurrently.
+///
+/// # Examples
+/// ```
+/// use fast_hex_lite::{backend, set_backend, Backend};
+///
+/// assert!(set_backend(Backend::Scalar));
+/// assert_eq!(backend(), Backend::Scalar);
+/// ```
+pub fn set_backend(backend: Backend) -> bool {
+    #[cfg(all(feature = "simd", not(feature = "forbid-unsafe")))]
+    {
+        crate::simd::set_backend(backend)
+    }
+    #[cfg(any(not(feature = "simd"), feature = "forbid-unsafe"))]
+    {
+        backend == Backend::Scalar
+    }
+}
+
+/// Output size from which non-temporal stores are used; `usize::MAX` is off.
+static STREAM_THRESHOLD: AtomicUsize = AtomicUsize::new(usize::MAX);
+
+/// Use non-temporal (streaming) stores for [`decode_to_slice`] and
+/// [`encode_to_slice`] outputs of at least `bytes` bytes, or turn them off
+/// with `None` (the default).
+///
+/// Streaming stores bypass the cache, so encoding or decoding a buffer much
+/// larger than the last-level cache does not evict the rest of the working
+/// set; below that size they are usually slower. Results are identical
+/// either way. Takes effect with the `simd` feature on `x86_64` (SSE2
+/// `movntdq` plus a final `sfence`) and is ignored elsewhere.
+///
+/// [`decode_to_slice`]: crate::decode_to_slice
+/// [`encode_to_slice`]: crate::encode_to_slice
+///
+/// # Examples
+/// ```
+/// use fast_hex_lite::{set_stream_threshold, stream_threshold};
+///
+/// set_stream_threshold(Some(64 << 20)); // outputs of 64 MiB and up
+/// assert_eq!(stream_threshold(), Some(64 << 20));
+/// set_stream_threshold(None);
+/// ```
+pub fn set_stream_threshold(bytes: Option<usize>) {
+    STREAM_THRESHOLD.store(bytes.unwrap_or(usize::MAX), Ordering::Relaxed);
+}
+
+/// The threshold set by [`set_stream_threshold`], or `None` if streaming
+/// stores are off.
+pub fn stream_threshold() -> Option<usize> {
+    match STREAM_THRESHOLD.load(Ordering::Relaxed) {
+        usize::MAX => None,
+        bytes => Some(bytes),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+    use super::*;
+
+    #[test]
+    fn test_backend_names_are_unique_and_displayed() {
+        let all = [
+            Backend::Scalar,
+            Backend::Sse2,
+            Backend::Neon,
+            Backend::Avx2,
+            Backend::Avx512,
+        ];
+        for (i, a) in all.iter().enumerate() {
+            assert_eq!(std::format!("{a}"), a.name());
+            for b in &all[i + 1..] {
+                assert_ne!(a.name(), b.name());
+            }
+        }
+    }
+
+    #[cfg(any(not(feature = "simd"), feature = "forbid-unsafe"))]
+    #[test]
+    fn test_scalar_only_build() {
+        assert_eq!(backend(), Backend::Scalar);
+        assert!(set_backend(Backend::Scalar));
+        assert!(!set_backend(Backend::Sse2));
+        assert!(!set_backend(Backend::Neon));
+        assert_eq!(backend(), Backend::Scalar);
+    }
+}
@@ -0,0 +1,356 @@
+//! Batch decode/encode of many fixed-size items (hashes, addresses).
+//!
+//! Short items are copied back-to-back into a stack block, so the SIMD
+//! kernels run across item boundaries and the scalar tail is paid once per
+//! block rather than once per item. Outputs are contiguous (`[[u8; N]]`
+//! flattens to `[u8]`), so a block decodes straight into its items.
+
+use crate::decode::decode_validated;
+use crate::{encoded_len, Error};
+
+/// Hex chars gathered per block (stack buffer size).
+const BATCH_HEX: usize = 512;
+
+/// Error from [`decode_batch`]: the first failing item and its error.
+///
+/// `error` is what [`decode_to_array`](crate::decode_to_array) would return
+/// for that item alone; [`Error::InvalidByte`] indices are into the item.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BatchError {
+    /// Zero-based index of the failing item.
+    pub item: usize,
+    /// The item's error.
+    pub error: Error,
+}
+
+impl core::fmt::Display for BatchError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "batch item {}: {}", self.item, self.error)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for BatchError {}
+
+/// Decode many `2*N`-char hex items into `N`-byte arrays.
+///
+/// Item `i` of `src_hex` is decoded into `dst[i]`. Every item is validated
+/// before anything is written, so on error `dst` is unchanged.
+///
+/// Returns the number of items decoded (`src_hex.len()`).
+///
+/// # Errors
+///
+/// Returns a [`BatchError`] for the first failing item:
+/// - [`Error::OddLength`] / [`Error::OutputTooSmall`] if its length is not
+///   `2*N` (same rules as [`decode_to_array`](crate::decode_to_array)),
+/// - [`Error::InvalidByte`] with the index inside that item.
+///
+/// If `dst` has fewer slots than `src_hex` has items, `item` is `dst.len()`
+/// and the error is [`Error::OutputTooSmall`].
+///
+/// # Examples
+/// ```
+/// use fast_hex_lite::{decode_batch, BatchError, Error};
+///
+/// let hashes: [&[u8]; 2] = [b"00ff", b"DEAD"];
+/// let mut out = [[0u8; 2]; 2];
+/// decode_batch(&hashes, &mut out).unwrap();
+/// assert_eq!(out, [[0x00, 0xff], [0xde, 0xad]]);
+///
+/// let bad: [&[u8]; 2] = [b"00ff", b"DExD"];
+/// assert_eq!(
+///     decode_batch(&bad, &mut out),
+///     Err(BatchError { item: 1, error: Error::InvalidByte { index: 2, byte: b'x' } })
+/// );
+/// ```
+pub fn decode_batch<const N: usize>(
+    src_hex: &[&[u8]],
+    dst: &mut [[u8; N]],
+) -> Result<usize, BatchError> {
+    let items = src_hex.len();
+    if dst.len() < items {
+        // Items before the first missing slot must still be reported first.
+        check_items::<N>(&src_hex[..dst.len()])?;
+        return Err(BatchError {
+            item: dst.len(),
+            error: Error::OutputTooSmall,
+        });
+    }
+
+    // Pass 1: lengths and validation, no writes.
+    check_items::<N>(src_hex)?;
+
+    // Pass 2: decode.
+    let item_hex = encoded_len(N);
+    if item_hex == 0 {
+        return Ok(items);
+    }
+    let dst = dst[..items].as_flattened_mut();
+    let per_block = BATCH_HEX / item_hex;
+    if per_block <= 1 {
+        for (src, out) in src_hex.iter().zip(dst.chunks_exact_mut(N)) {
+            decode_validated(src, out);
+        }
+        return Ok(items);
+    }
+
+    let mut buf = [0u8; BATCH_HEX];
+    for (srcs, out) in src_hex.chunks(per_block).zip(dst.chunks_mut(per_block * N)) {
+        let hex = gather(srcs, &mut buf);
+        decode_validated(hex, out);
+    }
+
+    Ok(items)
+}
+
+/// Encode many `N`-byte arrays into one buffer of back-to-back `2*N`-char
+/// hex items: item `i` lands at `dst_hex[i * 2 * N..(i + 1) * 2 * N]`.
+///
+/// `[[u8; N]]` is contiguous, so the whole batch is a single
+/// [`encode_to_slice`](crate::encode_to_slice) call with no per-item tail.
+///
+/// Returns the number of hex bytes written.
+///
+/// # Errors
+///
+/// Returns [`Error::OutputTooSmall`] if `dst_hex` is shorter than
+/// `src.len() * 2 * N`.
+///
+/// # Examples
+/// ```
+/// use fast_hex_lite::encode_batch;
+///
+/// let addrs = [[0xde, 0xad], [0xbe, 0xef]];
+/// let mut out = [0u8; 8];
+/// encode_batch(&addrs, &mut out, true).unwrap();
+/// assert_eq!(&out[..4], b"dead");
+/// assert_eq!(&out[4..], b"beef");
+/// ```
+pub fn encode_batch<const N: usize>(
+    src: &[[u8; N]],
+    dst_hex: &mut [u8],
+    lowercase: bool,
+) -> Result<usize, Error> {
+    crate::encode_to_slice(src.as_flattened(), dst_hex, lowercase)
+}
+
+// ── Helpers ────────────────────────────────────────────────────────────────
+
+/// Check lengths and validate `items` in order, reporting the first failure.
+fn check_items<const N: usize>(items: &[&[u8]]) -> Result<(), BatchError> {
+    let item_hex = encoded_len(N);
+    // Zero-sized items are checked one at a time (lengths only).
+    let per_block = BATCH_HEX.checked_div(item_hex).map_or(1, |n| n.max(1));
+
+    let mut buf = [0u8; BATCH_HEX];
+    for (b, block) in items.chunks(per_block).enumerate() {
+        let block_first = b * per_block;
+
+        // A wrong-length item ends the block: only items before it are
+        // validated, and its own error is reported if they are all fine.
+        let bad_len = block.iter().position(|s| s.len() != item_hex);
+        let good = &block[..bad_len.unwrap_or(block.len())];
+
+        let res = if per_block == 1 {
+            good.first()
+                .map_or(Ok(()), |s| crate::validate::validate_digits(s, 0))
+        } else {
+            crate::validate::validate_digits(gather(good, &mut buf), 0)
+        };
+        if let Err(Error::InvalidByte { index, byte }) = res {
+            return Err(BatchError {
+                item: block_first + index / item_hex,
+                error: Error::InvalidByte {
+                    index: index % item_hex,
+                    byte,
+                },
+            });
+        }
+        if let Some(k) = bad_len {
+            // Same precedence as `decode_to_array`: odd length first.
+            let error = crate::decoded_len(block[k].len())
+                .err()
+                .unwrap_or(Error::OutputTooSmall);
+            return Err(BatchError {
+                item: block_first + k,
+                error,
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Copy same-length `items` back-to-back into `buf`; returns the filled part.
+fn gather<'a>(items: &[&[u8]], buf: &'a mut [u8; BATCH_HEX]) -> &'a [u8] {
+    let mut len = 0;
+    for s in items {
+        buf[len..len + s.len()].copy_from_slice(s);
+        len += s.len();
+    }
+    &buf[..len]
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+    use super::*;
+    use std::prelude::v1::*;
+
+    fn hex_items<const N: usize>(count: usize) -> (Vec<[u8; N]>, Vec<Vec<u8>>) {
+        let raw: Vec<[u8; N]> = (0..count)
+            .map(|i| core::array::from_fn(|j| u8::try_from((i * 31 + j * 7) % 256).unwrap()))
+            .collect();
+        let hex = raw
+            .iter()
+            .enumerate()
+            .map(|(i, r)| {
+                let mut h = std::vec![0u8; 2 * N];
+                crate::encode_to_slice(r, &mut h, i % 2 == 0).unwrap();
+                h
+            })
+            .collect();
+        (raw, hex)
+    }
+
+    fn refs(v: &[Vec<u8>]) -> Vec<&[u8]> {
+        v.iter().map(Vec::as_slice).collect()
+    }
+
+    fn check_roundtrip<const N: usize>(count: usize) {
+        let (raw, hex) = hex_items::<N>(count);
+        let mut out = std::vec![[0u8; N]; count];
+        assert_eq!(decode_batch(&refs(&hex), &mut out), Ok(count));
+        assert_eq!(out, raw, "N={N} count={count}");
+    }
+
+    #[test]
+    fn test_decode_batch_roundtrip_common_sizes() {
+        for count in [0, 1, 2, 7, 8, 9, 33, 100] {
+            check_roundtrip::<20>(count);
+            check_roundtrip::<32>(count);
+            check_roundtrip::<1>(count);
+            check_roundtrip::<5>(count);
+            // Larger than a gather block: items are decoded in place.
+            check_roundtrip::<300>(count);
+        }
+    }
+
+    #[test]
+    fn test_decode_batch_zero_sized_items() {
+        let items: [&[u8]; 3] = [b"", b"", b""];
+        let mut out = [[0u8; 0]; 3];
+        assert_eq!(decode_batch(&items, &mut out), Ok(3));
+
+        let items: [&[u8]; 2] = [b"", b"ab"];
+        assert_eq!(
+            decode_batch(&items, &mut out),
+            Err(BatchError {
+                item: 1,
+                error: Error::OutputTooSmall
+            })
+        );
+    }
+
+    #[test]
+    fn test_decode_batch_invalid_byte_matches_per_item_decode() {
+        let (_, hex) = hex_items::<20>(40);
+        for (item, pos) in [(0, 0), (5, 39), (12, 17), (13, 0), (39, 20)] {
+            let mut bad = hex.clone();
+            bad[item][pos] = b'g';
+            // A later bad item must not win.
+            bad[39][1] = b'z';
+            let mut out = std::vec![[0xAAu8; 20]; 40];
+            let err = decode_batch(&refs(&bad), &mut out).unwrap_err();
+            assert_eq!(err.item, item);
+            assert_eq!(
+                Err(err.error),
+                crate::decode_to_array::<20>(&bad[item]),
+                "item {item} pos {pos}"
+            );
+            // No partial writes.
+            assert!(out.iter().all(|o| o.iter().all(|&b| b == 0xAA)));
+        }
+    }
+
+    #[test]
+    fn test_decode_batch_length_errors_in_order() {
+        let (_, mut hex) = hex_items::<4>(10);
+        hex[6].pop();
+        hex[8].truncate(6);
+        let mut out = std::vec![[0u8; 4]; 10];
+        assert_eq!(
+            decode_batch(&refs(&hex), &mut out),
+            Err(BatchError {
+                item: 6,
+                error: Error::OddLength
+            })
+        );
+
+        // An earlier invalid byte wins over a later bad length in the same block.
+        hex[3][2] = b'-';
+        assert_eq!(
+            decode_batch(&refs(&hex), &mut out),
+            Err(BatchError {
+                item: 3,
+                error: Error::InvalidByte {
+                    index: 2,
+                    byte: b'-'
+                }
+            })
+        );
+
+        let (_, mut hex) = hex_items::<4>(10);
+        hex[2].extend_from_slice(b"00");
+        assert_eq!(
+            decode_batch(&refs(&hex), &mut out),
+            Err(BatchError {
+                item: 2,
+                error: Error::OutputTooSmall
+            })
+        );
+    }
+
+    #[test]
+    fn test_decode_batch_too_few_slots() {
+        let (_, hex) = hex_items::<8>(5);
+        let mut out = std::vec![[0u8; 8]; 3];
+        assert_eq!(
+            decode_batch(&refs(&hex), &mut out),
+            Err(BatchError {
+                item: 3,
+                error: Error::OutputTooSmall
+            })
+        );
+    }
+
+    #[test]
+    fn test_encode_batch_matches_per_item() {
+        let (raw, _) = hex_items::<20>(17);
+        for lowercase in [true, false] {
+            let mut out = std::vec![0u8; 17 * 40];
+            assert_eq!(encode_batch(&raw, &mut out, lowercase), Ok(17 * 40));
+            for (r, h) in raw.iter().zip(out.chunks_exact(40)) {
+                let mut expect = [0u8; 40];
+                crate::encode_to_slice(r, &mut expect, lowercase).unwrap();
+                assert_eq!(h, expect);
+            }
+        }
+        assert_eq!(
+            encode_batch(&raw, &mut [0u8; 10], true),
+            Err(Error::OutputTooSmall)
+        );
+    }
+
+    #[test]
+    fn test_batch_error_display() {
+        let e = BatchError {
+            item: 4,
+            error: Error::OddLength,
+        };
+        assert_eq!(
+            std::format!("{e}"),
+            "batch item 4: hex string has odd length"
+        );
+    }
+}
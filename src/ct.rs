@@ -0,0 +1,243 @@
+//! Constant-time hex encoding/decoding for secret material (keys, MACs).
+//!
+//! The regular API indexes lookup tables with data-derived values and stops
+//! at the first invalid byte, which leaks through cache and timing side
+//! channels. Functions here use only arithmetic and masks:
+//!
+//! - no loads indexed by secret data
+//! - no branches on secret data
+//! - decoding scans the whole input and reports a single aggregate
+//!   [`Error::InvalidHex`], so the position of a bad byte is never revealed
+//!
+//! Only lengths (which are public) and the final valid/invalid outcome
+//! influence control flow. Throughput is lower than the table/SIMD paths;
+//! use these only where the input is secret.
+
+use crate::{decoded_len, encoded_len, Error};
+use core::hint::black_box;
+
+/// Constant-time variant of [`encode_to_slice`](crate::encode_to_slice).
+///
+/// Returns the number of bytes written on success.
+///
+/// # Errors
+///
+/// Returns [`Error::OutputTooSmall`] if `dst_hex` is not large enough.
+///
+/// # Examples
+/// ```
+/// use fast_hex_lite::ct::encode_to_slice_ct;
+///
+/// let key = [0xde, 0xad, 0xbe, 0xef];
+/// let mut out = [0u8; 8];
+/// encode_to_slice_ct(&key, &mut out, true).unwrap();
+/// assert_eq!(&out, b"deadbeef");
+/// ```
+pub fn encode_to_slice_ct(src: &[u8], dst_hex: &mut [u8], lowercase: bool) -> Result<usize, Error> {
+    let out_len = encoded_len(src.len());
+    if dst_hex.len() < out_len {
+        return Err(Error::OutputTooSmall);
+    }
+
+    // Distance from '9' + 1 to the first letter; `lowercase` is public.
+    let letter_off: u16 = if lowercase { 39 } else { 7 };
+
+    for (&byte, out_pair) in src.iter().zip(dst_hex[..out_len].chunks_exact_mut(2)) {
+        out_pair[0] = nibble_to_ascii(u16::from(byte >> 4), letter_off);
+        out_pair[1] = nibble_to_ascii(u16::from(byte & 0x0f), letter_off);
+    }
+
+    Ok(out_len)
+}
+
+/// Constant-time variant of [`decode_to_slice`](crate::decode_to_slice).
+///
+/// Every input byte is examined regardless of validity. On
+/// [`Error::InvalidHex`] the first `src_hex.len() / 2` bytes of `dst` are
+/// zeroed, so no partially decoded secret is left behind.
+///
+/// Returns the number of bytes written.
+///
+/// # Errors
+///
+/// - [`Error::OddLength`] if `src_hex.len()` is odd.
+/// - [`Error::OutputTooSmall`] if `dst` is too small.
+/// - [`Error::InvalidHex`] if any byte is not hex (position withheld).
+///
+/// # Examples
+/// ```
+/// use fast_hex_lite::{ct::decode_to_slice_ct, Error};
+///
+/// let mut key = [0u8; 4];
+/// decode_to_slice_ct(b"DEADbeef", &mut key).unwrap();
+/// assert_eq!(key, [0xde, 0xad, 0xbe, 0xef]);
+///
+/// assert_eq!(decode_to_slice_ct(b"de_dbeef", &mut key), Err(Error::InvalidHex));
+/// assert_eq!(key, [0; 4]);
+/// ```
+pub fn decode_to_slice_ct(src_hex: &[u8], dst: &mut [u8]) -> Result<usize, Error> {
+    let out_len = decoded_len(src_hex.len())?;
+    if dst.len() < out_len {
+        return Err(Error::OutputTooSmall);
+    }
+    let dst = &mut dst[..out_len];
+
+    // Accumulates the "invalid" mask of every byte; non-zero iff any was bad.
+    let mut bad: u16 = 0;
+    for (pair, out) in src_hex.chunks_exact(2).zip(dst.iter_mut()) {
+        let (hi, hi_bad) = ascii_to_nibble(pair[0]);
+        let (lo, lo_bad) = ascii_to_nibble(pair[1]);
+        bad |= hi_bad | lo_bad;
+        // Both nibbles are masked to 0..=15, so this always fits.
+        *out = u8::try_from(((hi << 4) | lo) & 0xFF).unwrap();
+    }
+
+    // The only data-dependent branch: the aggregate outcome.
+    if black_box(bad) != 0 {
+        dst.fill(0);
+        return Err(Error::InvalidHex);
+    }
+
+    Ok(out_len)
+}
+
+/// `0..=15` -> ASCII hex using a mask instead of a table lookup.
+#[inline]
+fn nibble_to_ascii(n: u16, letter_off: u16) -> u8 {
+    // (9 - n) underflows (sets the high bits) exactly when n > 9.
+    let is_letter = 0u16.wrapping_sub(9u16.wrapping_sub(n) >> 15);
+    let ascii = n + u16::from(b'0') + (is_letter & letter_off);
+    // `ascii` is at most 'f' (0x66).
+    u8::try_from(black_box(ascii) & 0xFF).unwrap()
+}
+
+/// ASCII -> nibble using masks only. Returns the nibble (0 if invalid) and an
+/// all-ones mask if `c` is not a hex digit, else 0.
+#[inline]
+fn ascii_to_nibble(c: u8) -> (u16, u16) {
+    let c = u16::from(c);
+    let l = c | 0x20;
+
+    // Range masks: `(lo - 1 - x) & (x - hi - 1)` has the sign bit set iff
+    // lo <= x <= hi. Arithmetic shift via i16 spreads it to all bits.
+    let in_range = |x: u16, lo: u16, hi: u16| {
+        let t = (lo.wrapping_sub(1).wrapping_sub(x)) & (x.wrapping_sub(hi).wrapping_sub(1));
+        (t.cast_signed() >> 15).cast_unsigned()
+    };
+    let digit = in_range(c, u16::from(b'0'), u16::from(b'9'));
+    let alpha = in_range(l, u16::from(b'a'), u16::from(b'f'));
+
+    let value =
+        (digit & c.wrapping_sub(u16::from(b'0'))) | (alpha & l.wrapping_sub(u16::from(b'a') - 10));
+    let invalid = !(digit | alpha);
+
+    (value & 0x0F, black_box(invalid))
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+    use super::*;
+    use std::prelude::v1::*;
+
+    fn all_bytes() -> Vec<u8> {
+        (0u8..=255).collect()
+    }
+
+    // ── encode ─────────────────────────────────────────────────────────────
+
+    #[test]
+    fn test_encode_ct_matches_regular_api() {
+        let src = all_bytes();
+        for lowercase in [true, false] {
+            let mut ct = std::vec![0u8; 512];
+            let mut regular = std::vec![0u8; 512];
+            assert_eq!(encode_to_slice_ct(&src, &mut ct, lowercase), Ok(512));
+            crate::encode_to_slice(&src, &mut regular, lowercase).unwrap();
+            assert_eq!(ct, regular, "lowercase={lowercase}");
+        }
+    }
+
+    #[test]
+    fn test_encode_ct_empty() {
+        assert_eq!(encode_to_slice_ct(&[], &mut [], true), Ok(0));
+    }
+
+    #[test]
+    fn test_encode_ct_output_too_small() {
+        let mut out = [0u8; 3];
+        assert_eq!(
+            encode_to_slice_ct(&[1, 2], &mut out, true),
+            Err(Error::OutputTooSmall)
+        );
+    }
+
+    // ── decode ─────────────────────────────────────────────────────────────
+
+    #[test]
+    fn test_decode_ct_matches_regular_api() {
+        let src = all_bytes();
+        for lowercase in [true, false] {
+            let mut hex = std::vec![0u8; 512];
+            crate::encode_to_slice(&src, &mut hex, lowercase).unwrap();
+            let mut ct = std::vec![0u8; 256];
+            assert_eq!(decode_to_slice_ct(&hex, &mut ct), Ok(256));
+            assert_eq!(ct, src);
+        }
+    }
+
+    #[test]
+    fn test_decode_ct_mixed_case() {
+        let mut out = [0u8; 4];
+        decode_to_slice_ct(b"DeAdBeEf", &mut out).unwrap();
+        assert_eq!(out, [0xde, 0xad, 0xbe, 0xef]);
+    }
+
+    #[test]
+    fn test_decode_ct_single_byte_validity_matches_regular_api() {
+        // Every byte value in both nibble positions.
+        for b in 0u8..=255 {
+            for hex in [[b, b'0'], [b'0', b]] {
+                let mut ct = [0u8; 1];
+                let mut regular = [0u8; 1];
+                let ct_res = decode_to_slice_ct(&hex, &mut ct);
+                let regular_res = crate::decode_to_slice(&hex, &mut regular);
+                match regular_res {
+                    Ok(n) => {
+                        assert_eq!(ct_res, Ok(n));
+                        assert_eq!(ct, regular);
+                    }
+                    Err(_) => assert_eq!(ct_res, Err(Error::InvalidHex), "byte {b:#04x}"),
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_decode_ct_error_is_aggregate_and_zeroes_output() {
+        let mut out = [0xAAu8; 4];
+        // Error position must not matter.
+        for hex in [b"Xeadbeef", b"deadbeeX", b"deXdbXef"] {
+            out.fill(0xAA);
+            assert_eq!(decode_to_slice_ct(hex, &mut out), Err(Error::InvalidHex));
+            assert_eq!(out, [0u8; 4]);
+        }
+    }
+
+    #[test]
+    fn test_decode_ct_length_errors() {
+        let mut out = [0u8; 1];
+        assert_eq!(decode_to_slice_ct(b"abc", &mut out), Err(Error::OddLength));
+        assert_eq!(
+            decode_to_slice_ct(b"abcd", &mut out),
+            Err(Error::OutputTooSmall)
+        );
+    }
+
+    #[test]
+    fn test_decode_ct_leaves_extra_dst_untouched() {
+        let mut out = [0xAAu8; 6];
+        assert_eq!(decode_to_slice_ct(b"0102", &mut out), Ok(2));
+        assert_eq!(out, [1, 2, 0xAA, 0xAA, 0xAA, 0xAA]);
+    }
+}
@@ -1,5 +1,6 @@
 //! Scalar hex decoder.
 
+use crate::validate::{validate_digits, validate_digits_case, CasePolicy};
 use crate::Error;
 
 /// Returns the number of bytes produced from a hex string of `hex_len` bytes.
@@ -19,6 +20,43 @@ pub fn decoded_len(hex_len: usize) -> Result<usize, Error> {
     }
 }
 
+/// How to decode an odd number of hex digits.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OddLengthPolicy {
+    /// [`Error::OddLength`], as for [`decode_to_slice`].
+    Reject,
+    /// Assume a leading zero nibble, reading the digits as a number:
+    /// `abc` decodes as `0abc` to `[0x0a, 0xbc]`.
+    PadLeft,
+    /// Assume a trailing zero nibble: `abc` decodes as `abc0` to
+    /// `[0xab, 0xc0]`.
+    PadRight,
+}
+
+/// Returns the number of bytes produced from `hex_len` hex digits under
+/// `policy`: `hex_len / 2` rounded up when padding, and
+/// [`decoded_len`] for `Reject`.
+///
+/// # Errors
+///
+/// Returns [`Error::OddLength`] if `hex_len` is odd and `policy` is `Reject`.
+///
+/// # Examples
+/// ```
+/// use fast_hex_lite::{decoded_len_odd, Error, OddLengthPolicy};
+///
+/// assert_eq!(decoded_len_odd(3, OddLengthPolicy::PadLeft), Ok(2));
+/// assert_eq!(decoded_len_odd(4, OddLengthPolicy::PadRight), Ok(2));
+/// assert_eq!(decoded_len_odd(3, OddLengthPolicy::Reject), Err(Error::OddLength));
+/// ```
+#[inline]
+pub fn decoded_len_odd(hex_len: usize, policy: OddLengthPolicy) -> Result<usize, Error> {
+    match policy {
+        OddLengthPolicy::Reject => decoded_len(hex_len),
+        OddLengthPolicy::PadLeft | OddLengthPolicy::PadRight => Ok(hex_len.div_ceil(2)),
+    }
+}
+
 /// Decode ASCII-hex bytes `src_hex` into `dst`.
 ///
 /// `src_hex` must contain an even number of bytes, all valid hex characters
@@ -31,18 +69,113 @@ pub fn decode_to_slice(src_hex: &[u8], dst: &mut [u8]) -> Result<usize, Error> {
     if dst.len() < out_len {
         return Err(Error::OutputTooSmall);
     }
-    #[cfg(feature = "simd")]
+    #[cfg(all(feature = "simd", not(feature = "forbid-unsafe")))]
     {
         crate::simd::decode_to_slice_simd(src_hex, &mut dst[..out_len])
     }
-    #[cfg(not(feature = "simd"))]
+    #[cfg(any(not(feature = "simd"), feature = "forbid-unsafe"))]
     {
         decode_scalar(src_hex, &mut dst[..out_len])
     }
 }
 
+/// Single-pass variant of [`decode_to_slice`] for inputs larger than cache.
+///
+/// Validation and decoding are fused: under `simd` the input is processed in
+/// L1-sized blocks that are validated and decoded back to back, so every
+/// input byte is read from memory once.
+///
+/// **On error `dst` may be partially written**: blocks before the failing
+/// one are decoded, the rest is unspecified. The error itself is exact, the
+/// same as [`decode_to_slice`] would report. Use this only if the buffer is
+/// discarded on error.
+///
+/// Returns the number of bytes written.
+///
+/// # Examples
+/// ```
+/// use fast_hex_lite::{decode_to_slice_fused, Error};
+///
+/// let mut buf = [0u8; 4];
+/// assert_eq!(decode_to_slice_fused(b"deadbeef", &mut buf), Ok(4));
+/// assert_eq!(buf, [0xde, 0xad, 0xbe, 0xef]);
+///
+/// let err = decode_to_slice_fused(b"deadbeeX", &mut buf).unwrap_err();
+/// assert_eq!(err, Error::InvalidByte { index: 7, byte: b'X' });
+/// ```
+#[inline]
+pub fn decode_to_slice_fused(src_hex: &[u8], dst: &mut [u8]) -> Result<usize, Error> {
+    let out_len = decoded_len(src_hex.len())?;
+    if dst.len() < out_len {
+        return Err(Error::OutputTooSmall);
+    }
+    #[cfg(all(feature = "simd", not(feature = "forbid-unsafe")))]
+    {
+        crate::simd::decode_fused_simd(src_hex, &mut dst[..out_len])
+    }
+    #[cfg(any(not(feature = "simd"), feature = "forbid-unsafe"))]
+    {
+        // Already single-pass: each pair is checked as it is decoded.
+        decode_scalar(src_hex, &mut dst[..out_len])
+    }
+}
+
+/// Decode trusted hex without validating it.
+///
+/// For input that is known to be valid, e.g. produced by
+/// [`encode_to_slice`](crate::encode_to_slice) or already checked with
+/// [`validate`](crate::validate). Skips the validation pass on the SIMD path
+/// and the per-pair error check on the scalar path. Lengths are still
+/// checked.
+///
+/// Invalid bytes are **not** undefined behavior: this function is safe and
+/// every read stays in bounds, but the decoded values are unspecified. With
+/// `debug_assertions` (tests, debug builds) the input is validated and the
+/// function panics on invalid hex.
+///
+/// Returns the number of bytes written.
+///
+/// # Examples
+/// ```
+/// use fast_hex_lite::{decode_to_slice_unchecked, encode_to_slice};
+///
+/// let mut hex = [0u8; 8];
+/// encode_to_slice(&[0xde, 0xad, 0xbe, 0xef], &mut hex, true).unwrap();
+///
+/// let mut buf = [0u8; 4];
+/// decode_to_slice_unchecked(&hex, &mut buf).unwrap();
+/// assert_eq!(buf, [0xde, 0xad, 0xbe, 0xef]);
+/// ```
+#[inline]
+pub fn decode_to_slice_unchecked(src_hex: &[u8], dst: &mut [u8]) -> Result<usize, Error> {
+    let out_len = decoded_len(src_hex.len())?;
+    if dst.len() < out_len {
+        return Err(Error::OutputTooSmall);
+    }
+    debug_assert!(
+        crate::validate(src_hex).is_ok(),
+        "decode_to_slice_unchecked: input is not valid hex"
+    );
+    #[cfg(all(feature = "simd", not(feature = "forbid-unsafe")))]
+    {
+        crate::simd::decode_validated_simd(src_hex, &mut dst[..out_len]);
+    }
+    #[cfg(any(not(feature = "simd"), feature = "forbid-unsafe"))]
+    {
+        decode_unchecked_scalar(src_hex, &mut dst[..out_len]);
+    }
+    Ok(out_len)
+}
+
 /// Decode exactly `N` bytes from a hex string of length `2*N`.
 ///
+/// The common crypto sizes (16, 20, 32 and 64 bytes) use fully unrolled
+/// kernels with no scalar tail; other sizes go through [`decode_to_slice`].
+/// Under `simd` the unrolled kernels always use the baseline SSE2 / NEON
+/// chunk, even when [`backend`](crate::backend) reports AVX2 or AVX-512;
+/// inputs this short gain nothing from the wider tiers. A scalar pin is
+/// still honoured.
+///
 /// Returns [`Error::OutputTooSmall`] if `src_hex.len() / 2 != N`.
 pub fn decode_to_array<const N: usize>(src_hex: &[u8]) -> Result<[u8; N], Error> {
     let out_len = decoded_len(src_hex.len())?;
@@ -50,17 +183,206 @@ pub fn decode_to_array<const N: usize>(src_hex: &[u8]) -> Result<[u8; N], Error>
         return Err(Error::OutputTooSmall);
     }
     let mut arr = [0u8; N];
-    decode_to_slice(src_hex, &mut arr)?;
+    if has_fixed_kernel(N) {
+        decode_fixed(src_hex, &mut arr)?;
+    } else {
+        decode_to_slice(src_hex, &mut arr)?;
+    }
     Ok(arr)
 }
 
+/// Sizes (in bytes) with a dedicated unrolled kernel in [`decode_to_array`]
+/// and [`encode_to_array`](crate::encode_to_array).
+#[inline]
+pub(crate) const fn has_fixed_kernel(n: usize) -> bool {
+    matches!(n, 16 | 20 | 32 | 64)
+}
+
+#[inline]
+fn decode_fixed(src_hex: &[u8], dst: &mut [u8]) -> Result<(), Error> {
+    #[cfg(all(feature = "simd", not(feature = "forbid-unsafe")))]
+    {
+        crate::simd::decode_fixed_base(src_hex, dst)
+    }
+    #[cfg(any(not(feature = "simd"), feature = "forbid-unsafe"))]
+    {
+        decode_fixed_scalar(src_hex, dst)
+    }
+}
+
 /// Decode hex bytes in-place: `buf` initially contains ASCII hex; after
 /// decoding, the first `buf.len() / 2` bytes hold the result.
 ///
 /// Returns the number of bytes written.
 #[inline]
 pub fn decode_in_place(buf: &mut [u8]) -> Result<usize, Error> {
+    decoded_len(buf.len())?;
+    #[cfg(all(feature = "simd", not(feature = "forbid-unsafe")))]
+    {
+        crate::simd::decode_in_place_simd(buf)
+    }
+    #[cfg(any(not(feature = "simd"), feature = "forbid-unsafe"))]
+    {
+        decode_in_place_scalar(buf)
+    }
+}
+
+/// Case-strict [`decode_to_slice`]: only the letters allowed by `case` are
+/// accepted, e.g. canonical lowercase for storage keys or uppercase for
+/// RFC 4648 base16. Validation runs on the same SIMD kernels as the regular
+/// decoder, with case-specific ranges, before anything is written.
+///
+/// Returns the number of bytes written.
+///
+/// # Errors
+///
+/// - [`Error::WrongCase`] for a hex letter of the other case.
+/// - Otherwise the errors of [`decode_to_slice`].
+///
+/// # Examples
+/// ```
+/// use fast_hex_lite::{decode_to_slice_case, CasePolicy, Error};
+///
+/// let mut out = [0u8; 4];
+/// decode_to_slice_case(b"DEADBEEF", &mut out, CasePolicy::Upper).unwrap();
+/// assert_eq!(out, [0xde, 0xad, 0xbe, 0xef]);
+///
+/// let err = decode_to_slice_case(b"deadBeef", &mut out, CasePolicy::Lower).unwrap_err();
+/// assert_eq!(err, Error::WrongCase { index: 4, byte: b'B' });
+/// ```
+pub fn decode_to_slice_case(
+    src_hex: &[u8],
+    dst: &mut [u8],
+    case: CasePolicy,
+) -> Result<usize, Error> {
+    let out_len = decoded_len(src_hex.len())?;
+    if dst.len() < out_len {
+        return Err(Error::OutputTooSmall);
+    }
+    validate_digits_case(src_hex, 0, case)?;
+    decode_validated(src_hex, &mut dst[..out_len]);
+    Ok(out_len)
+}
+
+/// Case-strict [`decode_in_place`]; see [`decode_to_slice_case`].
+///
+/// Returns the number of bytes written. On error `buf` is unchanged.
+///
+/// # Examples
+/// ```
+/// use fast_hex_lite::{decode_in_place_case, CasePolicy};
+///
+/// let mut buf = *b"deadbeef";
+/// let n = decode_in_place_case(&mut buf, CasePolicy::Lower).unwrap();
+/// assert_eq!(&buf[..n], &[0xde, 0xad, 0xbe, 0xef]);
+/// ```
+pub fn decode_in_place_case(buf: &mut [u8], case: CasePolicy) -> Result<usize, Error> {
     let out_len = decoded_len(buf.len())?;
+    validate_digits_case(buf, 0, case)?;
+    decode_validated_in_place(buf);
+    Ok(out_len)
+}
+
+/// [`decode_to_slice`] that also accepts an odd number of digits, padding
+/// the missing nibble as `policy` says (see [`OddLengthPolicy`]).
+///
+/// Even-length input decodes exactly as with [`decode_to_slice`]. Odd-length
+/// input is validated in full before anything is written and the bulk of
+/// it uses the same SIMD kernels; only the lone digit is handled separately.
+///
+/// Returns the number of bytes written ([`decoded_len_odd`]).
+///
+/// # Errors
+///
+/// - [`Error::OddLength`] for odd-length input under `Reject`.
+/// - [`Error::OutputTooSmall`] if `dst` is too small.
+/// - [`Error::InvalidByte`] for the first non-hex byte.
+///
+/// # Examples
+/// ```
+/// use fast_hex_lite::{decode_to_slice_odd, OddLengthPolicy};
+///
+/// let mut out = [0u8; 2];
+/// decode_to_slice_odd(b"abc", &mut out, OddLengthPolicy::PadLeft).unwrap();
+/// assert_eq!(out, [0x0a, 0xbc]);
+/// decode_to_slice_odd(b"abc", &mut out, OddLengthPolicy::PadRight).unwrap();
+/// assert_eq!(out, [0xab, 0xc0]);
+/// ```
+pub fn decode_to_slice_odd(
+    src_hex: &[u8],
+    dst: &mut [u8],
+    policy: OddLengthPolicy,
+) -> Result<usize, Error> {
+    let out_len = decoded_len_odd(src_hex.len(), policy)?;
+    if src_hex.len().is_multiple_of(2) {
+        return decode_to_slice(src_hex, dst);
+    }
+    if dst.len() < out_len {
+        return Err(Error::OutputTooSmall);
+    }
+    validate_digits(src_hex, 0)?;
+
+    let dst = &mut dst[..out_len];
+    if policy == OddLengthPolicy::PadLeft {
+        dst[0] = nibble(src_hex[0]);
+        decode_validated(&src_hex[1..], &mut dst[1..]);
+    } else {
+        let (pairs, last) = src_hex.split_at(src_hex.len() - 1);
+        decode_validated(pairs, &mut dst[..out_len - 1]);
+        dst[out_len - 1] = nibble(last[0]) << 4;
+    }
+    Ok(out_len)
+}
+
+/// [`decode_in_place`] that also accepts an odd number of digits; see
+/// [`decode_to_slice_odd`].
+///
+/// The first [`decoded_len_odd`] bytes of `buf` hold the result. Returns the
+/// number of bytes written. On error `buf` is unchanged.
+///
+/// # Examples
+/// ```
+/// use fast_hex_lite::{decode_in_place_odd, OddLengthPolicy};
+///
+/// let mut buf = *b"fff";
+/// let n = decode_in_place_odd(&mut buf, OddLengthPolicy::PadLeft).unwrap();
+/// assert_eq!(&buf[..n], &[0x0f, 0xff]);
+/// ```
+pub fn decode_in_place_odd(buf: &mut [u8], policy: OddLengthPolicy) -> Result<usize, Error> {
+    let out_len = decoded_len_odd(buf.len(), policy)?;
+    if buf.len().is_multiple_of(2) {
+        return decode_in_place(buf);
+    }
+    validate_digits(buf, 0)?;
+
+    // Both lone digits are read before anything is written: the pairs decode
+    // into the front of their own subslice, behind the digits they read.
+    if policy == OddLengthPolicy::PadLeft {
+        let first = nibble(buf[0]);
+        decode_validated_in_place(&mut buf[1..]);
+        buf[0] = first;
+    } else {
+        let last = nibble(buf[buf.len() - 1]) << 4;
+        let pairs = buf.len() - 1;
+        decode_validated_in_place(&mut buf[..pairs]);
+        buf[out_len - 1] = last;
+    }
+    Ok(out_len)
+}
+
+/// Value of an already-validated hex digit.
+#[inline]
+fn nibble(b: u8) -> u8 {
+    unhex_byte(b).unwrap()
+}
+
+// ── Scalar in-place decoder ────────────────────────────────────────────────
+
+#[cfg(any(not(feature = "simd"), feature = "forbid-unsafe", test))]
+#[inline]
+pub(crate) fn decode_in_place_scalar(buf: &mut [u8]) -> Result<usize, Error> {
+    // `buf` is already even-length checked by the caller.
+    let out_len = buf.len() >> 1;
 
     // Pass 1: validate without writing, so on error the buffer is unchanged.
     // Also lets us keep the fast decode loop branch-free.
@@ -93,6 +415,87 @@ pub fn decode_in_place(buf: &mut [u8]) -> Result<usize, Error> {
     Ok(out_len)
 }
 
+// ── Scalar fixed-size decoder ──────────────────────────────────────────────
+
+/// Unrolled scalar decode for `has_fixed_kernel` sizes (all multiples of 4):
+/// four pairs per step with a single error branch. With a constant length the
+/// loop unrolls completely.
+///
+/// May write to `dst` before an error is found, so it is only used on the
+/// scratch array of `decode_to_array`.
+#[cfg(any(not(feature = "simd"), feature = "forbid-unsafe", test))]
+#[inline]
+pub(crate) fn decode_fixed_scalar(src_hex: &[u8], dst: &mut [u8]) -> Result<(), Error> {
+    debug_assert_eq!(src_hex.len(), dst.len() * 2);
+    debug_assert!(dst.len().is_multiple_of(4));
+
+    for (g, (src8, out4)) in src_hex
+        .chunks_exact(8)
+        .zip(dst.chunks_exact_mut(4))
+        .enumerate()
+    {
+        let v = [
+            decode_pair(src8[0], src8[1]),
+            decode_pair(src8[2], src8[3]),
+            decode_pair(src8[4], src8[5]),
+            decode_pair(src8[6], src8[7]),
+        ];
+        if (v[0] | v[1] | v[2] | v[3]) & 0x0100 != 0 {
+            // Slow path: pinpoint the first bad byte of this group.
+            return crate::validate::validate_hex_scalar(src8, g * 8);
+        }
+        for (out, w) in out4.iter_mut().zip(v) {
+            // Valid pairs decode to 0x00..=0xFF.
+            *out = u8::try_from(w).unwrap();
+        }
+    }
+
+    Ok(())
+}
+
+// ── Scalar unchecked decoder ───────────────────────────────────────────────
+
+/// Decode without any error check: an invalid pair yields an unspecified
+/// byte (the low 8 bits of `decode_pair`).
+#[cfg(any(not(feature = "simd"), feature = "forbid-unsafe", test))]
+#[inline]
+pub(crate) fn decode_unchecked_scalar(src_hex: &[u8], dst: &mut [u8]) {
+    debug_assert_eq!(src_hex.len(), dst.len() * 2);
+
+    for (pair, out) in src_hex.chunks_exact(2).zip(dst.iter_mut()) {
+        *out = decode_pair(pair[0], pair[1]).to_le_bytes()[0];
+    }
+}
+
+/// Decode already-validated hex in place, into the front half of `buf`.
+fn decode_validated_in_place(buf: &mut [u8]) {
+    #[cfg(all(feature = "simd", not(feature = "forbid-unsafe")))]
+    {
+        crate::simd::decode_validated_in_place_simd(buf);
+    }
+    #[cfg(any(not(feature = "simd"), feature = "forbid-unsafe"))]
+    {
+        // Byte `i` is written after its two digits at `2i`, `2i + 1` are read.
+        for i in 0..buf.len() / 2 {
+            buf[i] = u8::try_from(decode_pair(buf[2 * i], buf[2 * i + 1])).unwrap();
+        }
+    }
+}
+
+/// Decode input that has already been validated.
+#[inline]
+pub(crate) fn decode_validated(src_hex: &[u8], dst: &mut [u8]) {
+    #[cfg(all(feature = "simd", not(feature = "forbid-unsafe")))]
+    {
+        crate::simd::decode_validated_simd(src_hex, dst);
+    }
+    #[cfg(any(not(feature = "simd"), feature = "forbid-unsafe"))]
+    {
+        let res = decode_scalar(src_hex, dst);
+        debug_assert!(res.is_ok());
+    }
+}
+
 // ── Scalar decoder ─────────────────────────────────────────────────────────
 
 #[inline]
@@ -100,11 +503,24 @@ pub(crate) fn decode_scalar(src_hex: &[u8], dst: &mut [u8]) -> Result<usize, Err
     // `src_hex` is already even-length checked by the caller.
     // `dst` is already sized-checked by the caller.
     let out_len = src_hex.len() >> 1;
-
-    // Hot loop: single 16-bit table lookup per output byte.
-    // Use a tight index-based loop so LLVM can eliminate bounds checks.
     let mut j = 0usize;
-    for out in dst.iter_mut().take(out_len) {
+
+    // `small-tables`: SWAR fast path, 8 hex chars -> 4 bytes per step without
+    // any table. A block with a bad byte drops to the pair loop below, which
+    // pinpoints the exact index.
+    #[cfg(feature = "small-tables")]
+    while j + 8 <= src_hex.len() {
+        let word = u64::from_le_bytes(src_hex[j..j + 8].try_into().unwrap());
+        let Some(bytes) = swar_decode8(word) else {
+            break;
+        };
+        dst[j / 2..j / 2 + 4].copy_from_slice(&bytes.to_le_bytes());
+        j += 8;
+    }
+
+    // Hot loop: one `decode_pair` per output byte (a single 16-bit table
+    // lookup by default). Use a tight loop so LLVM can eliminate bounds checks.
+    for out in &mut dst[j / 2..out_len] {
         let hi = src_hex[j];
         let lo = src_hex[j + 1];
 
@@ -146,14 +562,18 @@ pub(crate) fn unhex_byte(b: u8) -> Option<u8> {
 // 256-entry nibble table (0..=15) or 0xFF for invalid.
 const UNHEX_TABLE: [u8; 256] = make_unhex_table();
 
-// 65_536-entry pair table. Each entry encodes either:
+// 65_536-entry pair table (128 KiB). Each entry encodes either:
 // - valid: 0x0000..=0x00FF (decoded byte)
 // - invalid: 0x0100 (flag set)
 //
 // This lets the scalar decoder process 2 input bytes per iteration with a
-// single table lookup.
+// single table lookup. Not compiled with `small-tables`.
+#[cfg(not(feature = "small-tables"))]
 static HEXPAIR_TABLE: [u16; 65536] = make_hexpair_table();
 
+/// Decode an ASCII pair into `0x00..=0xFF`, or a value with `0x0100` set if
+/// either byte is not hex.
+#[cfg(not(feature = "small-tables"))]
 #[inline]
 fn decode_pair(hi: u8, lo: u8) -> u16 {
     // Index is the two ASCII bytes.
@@ -161,6 +581,65 @@ fn decode_pair(hi: u8, lo: u8) -> u16 {
     HEXPAIR_TABLE[idx]
 }
 
+/// Decode an ASCII pair into `0x00..=0xFF`, or a value with `0x0100` set if
+/// either byte is not hex.
+#[cfg(feature = "small-tables")]
+#[inline]
+fn decode_pair(hi: u8, lo: u8) -> u16 {
+    let h = unhex_arith(hi);
+    let l = unhex_arith(lo);
+    ((h | l) & 0x0100) | ((h & 0x0F) << 4) | (l & 0x0F)
+}
+
+/// Branchless nibble decode: `0..=15`, or `0x0100` for a non-hex byte.
+#[cfg(feature = "small-tables")]
+#[inline]
+fn unhex_arith(b: u8) -> u16 {
+    let digit = u16::from(b.wrapping_sub(b'0'));
+    let alpha = u16::from((b | 0x20).wrapping_sub(b'a'));
+
+    // All-ones masks selected by comparison results, no branches.
+    let digit_mask = 0u16.wrapping_sub(u16::from(digit < 10));
+    let alpha_mask = 0u16.wrapping_sub(u16::from(alpha < 6));
+
+    (digit & digit_mask) | ((alpha + 10) & alpha_mask) | (!(digit_mask | alpha_mask) & 0x0100)
+}
+
+/// SWAR decode of 8 ASCII hex chars (little-endian in `word`) into 4 bytes
+/// (little-endian in the result). Returns `None` if any char is not hex.
+#[cfg(feature = "small-tables")]
+#[inline]
+fn swar_decode8(word: u64) -> Option<u32> {
+    const ONES: u64 = 0x0101_0101_0101_0101;
+    const HIGH: u64 = ONES * 0x80;
+    const LOW_BYTES: u64 = 0x00FF_00FF_00FF_00FF;
+
+    // Per-byte range checks: with bit 7 cleared, adding `0x80 - lo` sets bit 7
+    // exactly when `byte >= lo`, and no add can carry into the next byte.
+    let x = word & !HIGH;
+    let in_range = |v: u64, lo: u8, hi: u8| {
+        (v + ONES * u64::from(0x80 - lo)) & !(v + ONES * u64::from(0x7F - hi))
+    };
+    let digit = in_range(x, b'0', b'9');
+    let alpha = in_range(x | (ONES * 0x20), b'a', b'f');
+
+    // Bytes >= 0x80 are never hex.
+    let valid = (digit | alpha) & !word & HIGH;
+    if valid != HIGH {
+        return None;
+    }
+
+    // nibble = (b & 0x0F) + (is_alpha ? 9 : 0)
+    let nibbles = (word & (ONES * 0x0F)) + ((alpha & HIGH) >> 7) * 9;
+
+    // Pack pairs per 16-bit word: (n_even << 4) | n_odd in the low byte.
+    let w = ((nibbles & LOW_BYTES) << 4) | ((nibbles >> 8) & LOW_BYTES);
+    // Gather the four low bytes into the bottom 32 bits.
+    let w = (w | (w >> 8)) & 0x0000_FFFF_0000_FFFF;
+    let w = (w | (w >> 16)) & 0xFFFF_FFFF;
+    Some(u32::try_from(w).unwrap())
+}
+
 const fn make_unhex_table() -> [u8; 256] {
     let mut t = [0xFFu8; 256];
 
@@ -186,6 +665,7 @@ const fn make_unhex_table() -> [u8; 256] {
     t
 }
 
+#[cfg(not(feature = "small-tables"))]
 #[allow(clippy::large_stack_arrays)]
 const fn make_hexpair_table() -> [u16; 65536] {
     let mut t = [0x0100u16; 65536];
@@ -19,6 +19,17 @@ fn test_empty() {
     assert_eq!(decode_to_slice(b"", &mut buf).unwrap(), 0);
 }
 
+/// Regression: the SSE2 validator used to reject every full 16-char chunk.
+#[cfg(all(target_arch = "x86_64", feature = "simd"))]
+#[test]
+fn test_simd_decode_valid_chunks() {
+    let hex = b"00112233445566778899aabbccddeeffDEADBEEF";
+    let mut buf = [0u8; 20];
+    assert_eq!(decode_to_slice(hex, &mut buf), Ok(20));
+    assert_eq!(buf[..4], [0x00, 0x11, 0x22, 0x33]);
+    assert_eq!(buf[16..], [0xde, 0xad, 0xbe, 0xef]);
+}
+
 #[test]
 fn test_single_byte() {
     let mut buf = [0u8; 1];
@@ -728,6 +739,7 @@ fn test_make_unhex_table_runtime_invalid() {
     }
 }
 
+#[cfg(not(feature = "small-tables"))]
 #[test]
 fn test_make_hexpair_table_runtime_valid_pair() {
     let t = make_hexpair_table();
@@ -742,6 +754,7 @@ fn test_make_hexpair_table_runtime_valid_pair() {
     assert_eq!(t[idx], 0x0000);
 }
 
+#[cfg(not(feature = "small-tables"))]
 #[test]
 fn test_make_hexpair_table_runtime_invalid_pair() {
     let t = make_hexpair_table();
@@ -752,6 +765,7 @@ fn test_make_hexpair_table_runtime_invalid_pair() {
     assert_eq!(t[idx] & 0x0100, 0x0100);
 }
 
+#[cfg(not(feature = "small-tables"))]
 #[test]
 fn test_make_hexpair_table_runtime_all_valid_pairs_no_error_flag() {
     let t = make_hexpair_table();
@@ -769,3 +783,437 @@ fn test_make_hexpair_table_runtime_all_valid_pairs_no_error_flag() {
         }
     }
 }
+
+// ── decode_pair / small-tables ─────────────────────────────────────────
+
+#[test]
+fn test_decode_pair_exhaustive() {
+    // Every (hi, lo) combination, whichever backend `decode_pair` compiles to.
+    for hi in 0u8..=255 {
+        for lo in 0u8..=255 {
+            let v = decode_pair(hi, lo);
+            match (unhex_byte(hi), unhex_byte(lo)) {
+                (Some(h), Some(l)) => assert_eq!(v, u16::from((h << 4) | l)),
+                _ => assert_eq!(v & 0x0100, 0x0100, "pair ({hi:#04x}, {lo:#04x})"),
+            }
+        }
+    }
+}
+
+#[cfg(not(feature = "small-tables"))]
+#[test]
+fn test_table_sizes_default() {
+    assert_eq!(core::mem::size_of_val(&HEXPAIR_TABLE), 128 * 1024);
+    assert_eq!(core::mem::size_of_val(&UNHEX_TABLE), 256);
+}
+
+#[cfg(feature = "small-tables")]
+#[test]
+fn test_unhex_arith_matches_table() {
+    for b in 0u8..=255 {
+        let expected = unhex_byte(b).map_or(0x0100, u16::from);
+        assert_eq!(unhex_arith(b), expected, "byte {b:#04x}");
+    }
+}
+
+#[cfg(feature = "small-tables")]
+#[test]
+fn test_swar_decode8_valid() {
+    let word = u64::from_le_bytes(*b"DeAdbeEF");
+    assert_eq!(
+        swar_decode8(word),
+        Some(u32::from_le_bytes([0xde, 0xad, 0xbe, 0xef]))
+    );
+    let word = u64::from_le_bytes(*b"00ff7f80");
+    assert_eq!(
+        swar_decode8(word),
+        Some(u32::from_le_bytes([0x00, 0xff, 0x7f, 0x80]))
+    );
+}
+
+#[cfg(feature = "small-tables")]
+#[test]
+fn test_swar_decode8_rejects_every_invalid_byte_in_every_lane() {
+    for lane in 0..8 {
+        for b in 0u8..=255 {
+            let mut chars = *b"01234567";
+            chars[lane] = b;
+            let got = swar_decode8(u64::from_le_bytes(chars));
+            if b.is_ascii_hexdigit() {
+                assert!(got.is_some(), "lane {lane} byte {b:#04x}");
+            } else {
+                assert_eq!(got, None, "lane {lane} byte {b:#04x}");
+            }
+        }
+    }
+}
+
+#[test]
+fn test_decode_scalar_error_index_in_every_position() {
+    // Covers the SWAR blocks, the pair loop tail, and the fall-back from a
+    // bad SWAR block to the pair loop.
+    let hex = b"0123456789abcdefABCDEF0123456789abcd";
+    for pos in 0..hex.len() {
+        let mut bad = *hex;
+        bad[pos] = b'x';
+        let mut dst = [0u8; 18];
+        assert_eq!(
+            decode_scalar(&bad, &mut dst),
+            Err(Error::InvalidByte {
+                index: pos,
+                byte: b'x'
+            })
+        );
+    }
+}
+
+// ── Fixed-size kernels (16 / 20 / 32 / 64 bytes) ───────────────────────────
+
+fn fixed_vector(n: usize) -> std::vec::Vec<u8> {
+    // Mixed case, every nibble value.
+    (0..2 * n)
+        .map(|i| b"0123456789abcdefABCDEF"[(i * 5) % 22])
+        .collect()
+}
+
+#[test]
+fn test_decode_fixed_scalar_matches_decode_scalar() {
+    for n in [16, 20, 32, 64] {
+        let hex = fixed_vector(n);
+        let mut fixed = std::vec![0u8; n];
+        let mut reference = std::vec![0u8; n];
+        decode_fixed_scalar(&hex, &mut fixed).unwrap();
+        decode_scalar(&hex, &mut reference).unwrap();
+        assert_eq!(fixed, reference, "n={n}");
+    }
+}
+
+#[test]
+fn test_decode_fixed_scalar_error_index_in_every_position() {
+    for n in [16, 20, 32, 64] {
+        let hex = fixed_vector(n);
+        for pos in 0..hex.len() {
+            let mut bad = hex.clone();
+            bad[pos] = b'g';
+            // A later bad byte in the same group must not win.
+            let after = (pos | 7).min(hex.len() - 1);
+            if after != pos {
+                bad[after] = b'z';
+            }
+            let mut dst = std::vec![0u8; n];
+            assert_eq!(
+                decode_fixed_scalar(&bad, &mut dst),
+                decode_scalar(&bad, &mut dst).map(|_| ()),
+                "n={n} pos={pos}"
+            );
+        }
+    }
+}
+
+#[test]
+fn test_decode_to_array_fixed_sizes_match_generic_path() {
+    fn check<const N: usize>() {
+        let hex = fixed_vector(N);
+        let mut expected = [0u8; N];
+        decode_to_slice(&hex, &mut expected).unwrap();
+        assert_eq!(decode_to_array::<N>(&hex), Ok(expected), "N={N}");
+
+        for pos in [0, N, 2 * N - 1] {
+            let mut bad = hex.clone();
+            bad[pos] = b' ';
+            assert_eq!(
+                decode_to_array::<N>(&bad),
+                Err(Error::InvalidByte {
+                    index: pos,
+                    byte: b' '
+                }),
+                "N={N} pos={pos}"
+            );
+        }
+    }
+    check::<16>();
+    check::<20>();
+    check::<32>();
+    check::<64>();
+    // Neighbouring sizes stay on the generic path.
+    check::<19>();
+    check::<33>();
+}
+
+// ── decode_to_slice_fused ──────────────────────────────────────────────────
+
+#[test]
+fn test_decode_fused_matches_decode_to_slice() {
+    let src: std::vec::Vec<u8> = (0..5000usize)
+        .map(|i| u8::try_from(i * 13 % 256).unwrap())
+        .collect();
+    let mut hex = std::vec![0u8; src.len() * 2];
+    crate::encode_to_slice(&src, &mut hex, false).unwrap();
+    for len in [0, 2, 14, 16, 30, 4094, 4096, 4098, 8192, 10_000] {
+        let mut fused = std::vec![0u8; len / 2];
+        assert_eq!(decode_to_slice_fused(&hex[..len], &mut fused), Ok(len / 2));
+        assert_eq!(fused, &src[..len / 2], "len={len}");
+    }
+}
+
+#[test]
+fn test_decode_fused_error_index_is_exact() {
+    let hex = std::vec![b'a'; 10_000];
+    for pos in [0, 1, 15, 4095, 4096, 4097, 9999] {
+        let mut bad = hex.clone();
+        bad[pos] = b'#';
+        // A later bad byte must not win.
+        bad[9998] = b'z';
+        let mut dst = std::vec![0u8; 5000];
+        let expected = decode_to_slice(&bad, &mut dst);
+        assert_eq!(decode_to_slice_fused(&bad, &mut dst), expected, "pos={pos}");
+    }
+}
+
+#[test]
+fn test_decode_fused_length_errors() {
+    let mut dst = [0u8; 1];
+    assert_eq!(
+        decode_to_slice_fused(b"abc", &mut dst),
+        Err(Error::OddLength)
+    );
+    assert_eq!(
+        decode_to_slice_fused(b"abcd", &mut dst),
+        Err(Error::OutputTooSmall)
+    );
+}
+
+// ── decode_to_slice_unchecked ──────────────────────────────────────────────
+
+#[test]
+fn test_decode_unchecked_matches_decode_to_slice() {
+    let src: std::vec::Vec<u8> = (0u8..=255).collect();
+    for lowercase in [true, false] {
+        let mut hex = std::vec![0u8; 512];
+        crate::encode_to_slice(&src, &mut hex, lowercase).unwrap();
+        for len in [0, 2, 14, 16, 18, 40, 66, 130, 512] {
+            let mut out = std::vec![0u8; len / 2];
+            assert_eq!(
+                decode_to_slice_unchecked(&hex[..len], &mut out),
+                Ok(len / 2)
+            );
+            assert_eq!(out, &src[..len / 2], "len={len}");
+        }
+    }
+}
+
+#[test]
+fn test_decode_unchecked_length_errors() {
+    let mut dst = [0u8; 1];
+    assert_eq!(
+        decode_to_slice_unchecked(b"abc", &mut dst),
+        Err(Error::OddLength)
+    );
+    assert_eq!(
+        decode_to_slice_unchecked(b"abcd", &mut dst),
+        Err(Error::OutputTooSmall)
+    );
+}
+
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic(expected = "input is not valid hex")]
+fn test_decode_unchecked_debug_asserts_validity() {
+    let mut dst = [0u8; 2];
+    let _ = decode_to_slice_unchecked(b"zz00", &mut dst);
+}
+
+#[test]
+fn test_decode_unchecked_scalar_garbage_is_not_ub() {
+    // Every byte value in both positions: no panic, valid pairs still exact.
+    for b in 0u8..=255 {
+        for pair in [[b, b'7'], [b'7', b]] {
+            let mut out = [0u8; 1];
+            decode_unchecked_scalar(&pair, &mut out);
+            let mut expect = [0u8; 1];
+            if decode_scalar(&pair, &mut expect).is_ok() {
+                assert_eq!(out, expect);
+            }
+        }
+    }
+}
+
+// ── Case-strict decode ─────────────────────────────────────────────────────
+
+#[test]
+fn test_decode_case_strict() {
+    let src: std::vec::Vec<u8> = (0..=255u8).collect();
+    let mut lower = std::vec![0u8; 512];
+    let mut upper = std::vec![0u8; 512];
+    crate::encode_to_slice(&src, &mut lower, true).unwrap();
+    crate::encode_to_slice(&src, &mut upper, false).unwrap();
+
+    for len in [0, 2, 16, 30, 64, 130, 512] {
+        let mut dst = std::vec![0u8; len / 2];
+        for (hex, case) in [
+            (&lower, CasePolicy::Lower),
+            (&upper, CasePolicy::Upper),
+            (&lower, CasePolicy::Any),
+            (&upper, CasePolicy::Any),
+        ] {
+            assert_eq!(
+                decode_to_slice_case(&hex[..len], &mut dst, case),
+                Ok(len / 2)
+            );
+            assert_eq!(dst, src[..len / 2]);
+
+            let mut buf = hex[..len].to_vec();
+            assert_eq!(decode_in_place_case(&mut buf, case), Ok(len / 2));
+            assert_eq!(buf[..len / 2], src[..len / 2]);
+        }
+    }
+
+    // The first letter of the other case is reported, not `InvalidByte`.
+    let first_letter = lower.iter().position(u8::is_ascii_alphabetic).unwrap();
+    let mut dst = [0xAAu8; 256];
+    assert_eq!(
+        decode_to_slice_case(&lower, &mut dst, CasePolicy::Upper),
+        Err(Error::WrongCase {
+            index: first_letter,
+            byte: lower[first_letter]
+        })
+    );
+    assert_eq!(dst, [0xAA; 256]);
+    let mut buf = upper.clone();
+    assert_eq!(
+        decode_in_place_case(&mut buf, CasePolicy::Lower),
+        Err(Error::WrongCase {
+            index: first_letter,
+            byte: upper[first_letter]
+        })
+    );
+    assert_eq!(buf, upper);
+}
+
+#[test]
+fn test_decode_case_error_order() {
+    let mut dst = [0u8; 8];
+    // Whichever comes first wins.
+    assert_eq!(
+        decode_to_slice_case(b"00g0A0aa", &mut dst, CasePolicy::Lower),
+        Err(Error::InvalidByte {
+            index: 2,
+            byte: b'g'
+        })
+    );
+    assert_eq!(
+        decode_to_slice_case(b"00A0g0aa", &mut dst, CasePolicy::Lower),
+        Err(Error::WrongCase {
+            index: 2,
+            byte: b'A'
+        })
+    );
+    // Non-letters are never a case error; `Any` never reports one.
+    assert_eq!(
+        decode_to_slice_case(b"00G0", &mut dst, CasePolicy::Upper),
+        Err(Error::InvalidByte {
+            index: 2,
+            byte: b'G'
+        })
+    );
+    assert_eq!(
+        decode_to_slice_case(b"aAbB", &mut dst, CasePolicy::Any),
+        Ok(2)
+    );
+    assert_eq!(
+        decode_to_slice_case(b"abc", &mut dst, CasePolicy::Lower),
+        Err(Error::OddLength)
+    );
+    assert_eq!(
+        decode_to_slice_case(b"abcd", &mut dst[..1], CasePolicy::Lower),
+        Err(Error::OutputTooSmall)
+    );
+    assert_eq!(crate::validate_case(b"ABCD", CasePolicy::Upper), Ok(()));
+}
+
+#[test]
+fn test_decoded_len_odd() {
+    for policy in [OddLengthPolicy::PadLeft, OddLengthPolicy::PadRight] {
+        assert_eq!(decoded_len_odd(0, policy), Ok(0));
+        assert_eq!(decoded_len_odd(1, policy), Ok(1));
+        assert_eq!(decoded_len_odd(2, policy), Ok(1));
+        assert_eq!(decoded_len_odd(7, policy), Ok(4));
+    }
+    assert_eq!(decoded_len_odd(8, OddLengthPolicy::Reject), Ok(4));
+    assert_eq!(
+        decoded_len_odd(7, OddLengthPolicy::Reject),
+        Err(Error::OddLength)
+    );
+}
+
+#[test]
+fn test_decode_odd_matches_explicit_padding() {
+    let src: std::vec::Vec<u8> = (0..600u32)
+        .map(|i| u8::try_from(i * 53 % 256).unwrap())
+        .collect();
+    let mut hex = std::vec![0u8; 1200];
+    crate::encode_to_slice(&src, &mut hex, false).unwrap();
+
+    // Odd lengths around the SIMD block sizes, plus even ones.
+    for len in [0, 1, 2, 3, 15, 17, 31, 33, 63, 64, 65, 127, 129, 513, 1199] {
+        let digits = &hex[..len];
+        for (policy, padded) in [
+            (OddLengthPolicy::PadLeft, [&b"0"[..], digits].concat()),
+            (OddLengthPolicy::PadRight, [digits, &b"0"[..]].concat()),
+        ] {
+            let padded = if len.is_multiple_of(2) {
+                digits.to_vec()
+            } else {
+                padded
+            };
+            let mut expected = std::vec![0u8; padded.len() / 2];
+            decode_to_slice(&padded, &mut expected).unwrap();
+
+            let mut dst = std::vec![0xAAu8; expected.len() + 1];
+            assert_eq!(
+                decode_to_slice_odd(digits, &mut dst, policy),
+                Ok(expected.len())
+            );
+            assert_eq!(dst[..expected.len()], expected[..]);
+
+            let mut buf = digits.to_vec();
+            assert_eq!(decode_in_place_odd(&mut buf, policy), Ok(expected.len()));
+            assert_eq!(buf[..expected.len()], expected[..]);
+        }
+    }
+}
+
+#[test]
+fn test_decode_odd_errors() {
+    let mut dst = [0xAAu8; 4];
+    assert_eq!(
+        decode_to_slice_odd(b"abc", &mut dst, OddLengthPolicy::Reject),
+        Err(Error::OddLength)
+    );
+    assert_eq!(
+        decode_to_slice_odd(b"abcd", &mut dst, OddLengthPolicy::Reject),
+        Ok(2)
+    );
+    assert_eq!(
+        decode_to_slice_odd(b"abc", &mut dst[..1], OddLengthPolicy::PadLeft),
+        Err(Error::OutputTooSmall)
+    );
+
+    // The lone digit is validated like any other, at its own index.
+    let mut dst = [0xAAu8; 4];
+    for (src, index, byte) in [
+        (&b"gbc"[..], 0, b'g'),
+        (b"abg", 2, b'g'),
+        (b"a bcd", 1, b' '),
+    ] {
+        for policy in [OddLengthPolicy::PadLeft, OddLengthPolicy::PadRight] {
+            let err = Err(Error::InvalidByte { index, byte });
+            assert_eq!(decode_to_slice_odd(src, &mut dst, policy), err);
+            assert_eq!(dst, [0xAA; 4]);
+
+            let mut buf = src.to_vec();
+            assert_eq!(decode_in_place_odd(&mut buf, policy), err);
+            assert_eq!(buf, src);
+        }
+    }
+}
@@ -0,0 +1,262 @@
+//! EIP-55 mixed-case checksum addresses, with the EIP-1191 chain-id variant.
+//!
+//! An Ethereum address is 20 bytes. Its checksummed form is the 40-digit
+//! lowercase hex with every letter uppercased where the matching nibble of
+//! the Keccak-256 of that lowercase hex is 8 or more. EIP-1191 hashes
+//! `"<chain id>0x<lowercase hex>"` instead, so the checksum differs per chain.
+//!
+//! Keccak-256 is built in, so the module needs neither `std` nor another
+//! crate.
+
+use crate::{decode_to_array_prefixed, Error, PrefixPolicy};
+
+mod keccak;
+
+/// Length of an address in bytes.
+pub const ADDRESS_LEN: usize = 20;
+
+/// Length of a checksummed address in hex digits, without the `0x` prefix.
+pub const HEX_LEN: usize = 40;
+
+/// Longest EIP-1191 hash input: a `u64` chain id, `0x`, the address.
+const CHAIN_INPUT_MAX: usize = 20 + 2 + HEX_LEN;
+
+/// Encode `addr` as 40 hex digits with the EIP-55 checksum, or the EIP-1191
+/// checksum for `chain_id`. No `0x` prefix is written.
+///
+/// # Examples
+/// ```
+/// use fast_hex_lite::eip55;
+///
+/// let addr: [u8; 20] =
+///     fast_hex_lite::decode_to_array(b"5aaeb6053f3e94c9b9a09f33669435e7ef1beaed").unwrap();
+/// assert_eq!(
+///     &eip55::encode_to_array(&addr, None),
+///     b"5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed"
+/// );
+/// // EIP-1191, chain id 30.
+/// assert_eq!(
+///     &eip55::encode_to_array(&addr, Some(30)),
+///     b"5aaEB6053f3e94c9b9a09f33669435E7ef1bEAeD"
+/// );
+/// ```
+pub fn encode_to_array(addr: &[u8; ADDRESS_LEN], chain_id: Option<u64>) -> [u8; HEX_LEN] {
+    let mut hex: [u8; HEX_LEN] = crate::encode_to_array(addr, true);
+    let hash = checksum_hash(&hex, chain_id);
+    for (i, c) in hex.iter_mut().enumerate() {
+        if c.is_ascii_alphabetic() && uppercase_at(&hash, i) {
+            c.make_ascii_uppercase();
+        }
+    }
+    hex
+}
+
+/// Decode a checksummed address, with or without `0x`, and verify its
+/// checksum (EIP-55, or EIP-1191 for `chain_id`).
+///
+/// The checksum is strict: all-lowercase or all-uppercase input only passes
+/// if it happens to match. Use
+/// [`decode_to_array_prefixed`] to accept addresses without a checksum.
+///
+/// # Errors
+///
+/// - [`Error::InvalidByte`], [`Error::OddLength`] for input that is not hex.
+/// - [`Error::OutputTooSmall`] if it is not exactly 40 digits.
+/// - [`Error::WrongCase`] for the first letter whose case does not match the
+///   checksum.
+///
+/// Indices point into `src_hex`, prefix included.
+///
+/// # Examples
+/// ```
+/// use fast_hex_lite::{eip55, Error};
+///
+/// let addr = eip55::decode_to_array(b"0xfB6916095ca1df60bB79Ce92cE3Ea74c37c5d359", None).unwrap();
+/// assert_eq!(addr[0], 0xfb);
+///
+/// let err = eip55::decode_to_array(b"0xfb6916095ca1df60bB79Ce92cE3Ea74c37c5d359", None);
+/// assert_eq!(err, Err(Error::WrongCase { index: 3, byte: b'b' }));
+/// ```
+pub fn decode_to_array(src_hex: &[u8], chain_id: Option<u64>) -> Result<[u8; ADDRESS_LEN], Error> {
+    let addr = decode_to_array_prefixed(src_hex, PrefixPolicy::Optional)?;
+
+    // The digits decoded to exactly 20 bytes, so they are the last 40 bytes
+    // and differ from the expected form in letter case only.
+    let offset = src_hex.len() - HEX_LEN;
+    let expected = encode_to_array(&addr, chain_id);
+    for (i, (&got, &want)) in src_hex[offset..].iter().zip(&expected).enumerate() {
+        if got != want {
+            return Err(Error::WrongCase {
+                index: offset + i,
+                byte: got,
+            });
+        }
+    }
+    Ok(addr)
+}
+
+/// Check a checksummed address without returning it; see
+/// [`decode_to_array`].
+///
+/// # Errors
+///
+/// See [`decode_to_array`].
+///
+/// # Examples
+/// ```
+/// use fast_hex_lite::eip55;
+///
+/// assert!(eip55::verify(b"0x52908400098527886E0F7030069857D2E4169EE7", None).is_ok());
+/// assert!(eip55::verify(b"0x52908400098527886E0F7030069857D2E4169EE7", Some(30)).is_err());
+/// ```
+#[inline]
+pub fn verify(src_hex: &[u8], chain_id: Option<u64>) -> Result<(), Error> {
+    decode_to_array(src_hex, chain_id).map(|_| ())
+}
+
+/// Keccak-256 of the lowercase hex, prefixed with `"<chain id>0x"` for
+/// EIP-1191.
+fn checksum_hash(lower_hex: &[u8; HEX_LEN], chain_id: Option<u64>) -> [u8; 32] {
+    let Some(mut id) = chain_id else {
+        return keccak::keccak256(lower_hex);
+    };
+
+    // Decimal digits of the chain id, written backwards.
+    let mut digits = [0u8; 20];
+    let mut n = 0;
+    loop {
+        digits[n] = b'0' + u8::try_from(id % 10).unwrap();
+        n += 1;
+        id /= 10;
+        if id == 0 {
+            break;
+        }
+    }
+
+    let mut input = [0u8; CHAIN_INPUT_MAX];
+    for (dst, &d) in input.iter_mut().zip(digits[..n].iter().rev()) {
+        *dst = d;
+    }
+    input[n..n + 2].copy_from_slice(b"0x");
+    input[n + 2..n + 2 + HEX_LEN].copy_from_slice(lower_hex);
+    keccak::keccak256(&input[..n + 2 + HEX_LEN])
+}
+
+/// `true` if hash nibble `i` (high nibble first) is 8 or more.
+#[inline]
+fn uppercase_at(hash: &[u8; 32], i: usize) -> bool {
+    let bit = if i.is_multiple_of(2) { 0x80 } else { 0x08 };
+    hash[i / 2] & bit != 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Checksummed addresses from EIP-55.
+    const EIP55: [&[u8; HEX_LEN]; 8] = [
+        b"5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed",
+        b"fB6916095ca1df60bB79Ce92cE3Ea74c37c5d359",
+        b"dbF03B407c01E7cD3CBea99509d93f8DDDC8C6FB",
+        b"D1220A0cf47c7B9Be7A2E6BA89F429762e7b9aDb",
+        b"52908400098527886E0F7030069857D2E4169EE7",
+        b"8617E340B3D01FA5F11F306F4090FD50E238070D",
+        b"de709f2102306220921060314715629080e2fb77",
+        b"27b1fdb04752bbc536007a920d24acb045561c26",
+    ];
+
+    /// EIP-1191 vectors for chain ids 30 and 31 (RSK), plus a few more ids.
+    const EIP1191: [(u64, &[u8; HEX_LEN]); 9] = [
+        (30, b"5aaEB6053f3e94c9b9a09f33669435E7ef1bEAeD"),
+        (30, b"Fb6916095cA1Df60bb79ce92cE3EA74c37c5d359"),
+        (30, b"52908400098527886E0F7030069857D2E4169ee7"),
+        (31, b"5aAeb6053F3e94c9b9A09F33669435E7EF1BEaEd"),
+        (31, b"Fb6916095CA1dF60bb79CE92ce3Ea74C37c5D359"),
+        (31, b"52908400098527886E0F7030069857D2e4169EE7"),
+        // Chain id formatting: zero, three digits, the widest `u64`.
+        (0, b"5AAEB6053F3e94c9B9A09f33669435e7EF1beAED"),
+        (137, b"5AAeB6053F3e94c9B9A09F33669435E7EF1BeAEd"),
+        (u64::MAX, b"5aAEb6053f3E94c9b9A09f33669435E7eF1BeAed"),
+    ];
+
+    fn addr_of(hex: &[u8]) -> [u8; ADDRESS_LEN] {
+        crate::decode_to_array(hex).unwrap()
+    }
+
+    #[test]
+    fn test_eip55_vectors() {
+        for hex in EIP55 {
+            let addr = addr_of(hex);
+            assert_eq!(&encode_to_array(&addr, None), hex);
+            assert_eq!(decode_to_array(hex, None), Ok(addr));
+
+            let mut prefixed = [0u8; HEX_LEN + 2];
+            prefixed[..2].copy_from_slice(b"0x");
+            prefixed[2..].copy_from_slice(hex);
+            assert_eq!(verify(&prefixed, None), Ok(()));
+        }
+    }
+
+    #[test]
+    fn test_eip1191_vectors() {
+        for (chain_id, hex) in EIP1191 {
+            let addr = addr_of(hex);
+            assert_eq!(&encode_to_array(&addr, Some(chain_id)), hex);
+            assert_eq!(verify(hex, Some(chain_id)), Ok(()));
+        }
+        let addr = addr_of(EIP55[0]);
+        for chain_id in [1, 9, 10, 1_000_000_007] {
+            let hex = encode_to_array(&addr, Some(chain_id));
+            assert_eq!(decode_to_array(&hex, Some(chain_id)), Ok(addr));
+        }
+    }
+
+    #[test]
+    fn test_verify_reports_first_wrong_letter() {
+        let hex = *EIP55[0];
+        for i in 0..HEX_LEN {
+            if !hex[i].is_ascii_alphabetic() {
+                continue;
+            }
+            let mut bad = hex;
+            bad[i] ^= 0x20;
+            assert_eq!(
+                verify(&bad, None),
+                Err(Error::WrongCase {
+                    index: i,
+                    byte: bad[i]
+                })
+            );
+        }
+
+        // All lowercase: the first letter that should be uppercase.
+        let lower = hex.to_ascii_lowercase();
+        let first = hex.iter().position(u8::is_ascii_uppercase).unwrap();
+        assert_eq!(
+            verify(&lower, None),
+            Err(Error::WrongCase {
+                index: first,
+                byte: lower[first]
+            })
+        );
+        // EIP-55 and EIP-1191 checksums are not interchangeable.
+        assert!(verify(&hex, Some(1)).is_err());
+    }
+
+    #[test]
+    fn test_decode_errors() {
+        let hex = *EIP55[1];
+        let mut bad = hex;
+        bad[7] = b'g';
+        assert_eq!(
+            verify(&bad, None),
+            Err(Error::InvalidByte {
+                index: 7,
+                byte: b'g'
+            })
+        );
+        assert_eq!(verify(&hex[..38], None), Err(Error::OutputTooSmall));
+        assert_eq!(verify(&hex[..39], None), Err(Error::OddLength));
+        assert_eq!(verify(b"", None), Err(Error::OutputTooSmall));
+    }
+}
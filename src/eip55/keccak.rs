@@ -0,0 +1,162 @@
+//! Keccak-256 (the original Keccak padding used by Ethereum, not SHA3-256).
+//!
+//! A plain, portable keccak-f[1600]; checksums hash at most a few dozen
+//! bytes, so there is no need for anything faster.
+
+/// Sponge rate in bytes for a 256-bit output (1600 - 2 * 256 bits).
+const RATE: usize = 136;
+
+const ROUND_CONSTANTS: [u64; 24] = [
+    0x0000_0000_0000_0001,
+    0x0000_0000_0000_8082,
+    0x8000_0000_0000_808A,
+    0x8000_0000_8000_8000,
+    0x0000_0000_0000_808B,
+    0x0000_0000_8000_0001,
+    0x8000_0000_8000_8081,
+    0x8000_0000_0000_8009,
+    0x0000_0000_0000_008A,
+    0x0000_0000_0000_0088,
+    0x0000_0000_8000_8009,
+    0x0000_0000_8000_000A,
+    0x0000_0000_8000_808B,
+    0x8000_0000_0000_008B,
+    0x8000_0000_0000_8089,
+    0x8000_0000_0000_8003,
+    0x8000_0000_0000_8002,
+    0x8000_0000_0000_0080,
+    0x0000_0000_0000_800A,
+    0x8000_0000_8000_000A,
+    0x8000_0000_8000_8081,
+    0x8000_0000_0000_8080,
+    0x0000_0000_8000_0001,
+    0x8000_0000_8000_8008,
+];
+
+/// Rho rotations, in the lane order visited by `PI`.
+const RHO: [u32; 24] = [
+    1, 3, 6, 10, 15, 21, 28, 36, 45, 55, 2, 14, 27, 41, 56, 8, 25, 43, 62, 18, 39, 61, 20, 44,
+];
+
+/// Pi lane permutation as a single cycle starting from lane 1.
+const PI: [usize; 24] = [
+    10, 7, 11, 17, 18, 3, 5, 16, 8, 21, 24, 4, 15, 23, 19, 13, 12, 2, 20, 14, 22, 9, 6, 1,
+];
+
+/// Keccak-256 of `data`.
+pub(super) fn keccak256(data: &[u8]) -> [u8; 32] {
+    sponge(data, 0x01)
+}
+
+/// Absorb `data` with the domain padding byte `pad` (0x01 for Keccak, 0x06
+/// for SHA3) and squeeze 32 bytes.
+fn sponge(data: &[u8], pad: u8) -> [u8; 32] {
+    let mut state = [0u64; 25];
+
+    let (blocks, rest) = data.as_chunks::<RATE>();
+    for block in blocks {
+        absorb(&mut state, block);
+        keccak_f(&mut state);
+    }
+
+    let mut last = [0u8; RATE];
+    last[..rest.len()].copy_from_slice(rest);
+    last[rest.len()] ^= pad;
+    last[RATE - 1] ^= 0x80;
+    absorb(&mut state, &last);
+    keccak_f(&mut state);
+
+    let mut out = [0u8; 32];
+    for (bytes, lane) in out.as_chunks_mut::<8>().0.iter_mut().zip(state) {
+        *bytes = lane.to_le_bytes();
+    }
+    out
+}
+
+fn absorb(state: &mut [u64; 25], block: &[u8; RATE]) {
+    for (lane, bytes) in state.iter_mut().zip(block.as_chunks::<8>().0) {
+        *lane ^= u64::from_le_bytes(*bytes);
+    }
+}
+
+/// The keccak-f[1600] permutation. Lane `(x, y)` is `state[x + 5 * y]`.
+fn keccak_f(state: &mut [u64; 25]) {
+    for rc in ROUND_CONSTANTS {
+        // Theta
+        let mut c = [0u64; 5];
+        for (x, col) in c.iter_mut().enumerate() {
+            *col = state[x] ^ state[x + 5] ^ state[x + 10] ^ state[x + 15] ^ state[x + 20];
+        }
+        for x in 0..5 {
+            let d = c[(x + 4) % 5] ^ c[(x + 1) % 5].rotate_left(1);
+            for y in 0..5 {
+                state[x + 5 * y] ^= d;
+            }
+        }
+
+        // Rho and pi
+        let mut carry = state[1];
+        for (&lane, &rot) in PI.iter().zip(&RHO) {
+            let next = state[lane];
+            state[lane] = carry.rotate_left(rot);
+            carry = next;
+        }
+
+        // Chi
+        for row in state.as_chunks_mut::<5>().0 {
+            let r = *row;
+            for x in 0..5 {
+                row[x] = r[x] ^ (!r[(x + 1) % 5] & r[(x + 2) % 5]);
+            }
+        }
+
+        // Iota
+        state[0] ^= rc;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hex32(s: &str) -> [u8; 32] {
+        let mut out = [0u8; 32];
+        crate::decode_to_slice(s.as_bytes(), &mut out).unwrap();
+        out
+    }
+
+    fn pattern<const N: usize>() -> [u8; N] {
+        core::array::from_fn(|i| u8::try_from(i * 7 % 256).unwrap())
+    }
+
+    #[test]
+    fn test_keccak256_vectors() {
+        assert_eq!(
+            keccak256(b""),
+            hex32("c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470")
+        );
+        assert_eq!(
+            keccak256(b"abc"),
+            hex32("4e03657aea45a94fc7d47ba826c8d667c0d1e6e33a64a036ec44f58fa12d6c45")
+        );
+        // Several blocks.
+        assert_eq!(
+            keccak256(&pattern::<300>()),
+            hex32("e8d0c605aa90a1fe3feb493ad761f5313ddd79b377b06ed215e8c883341c33ae")
+        );
+    }
+
+    #[test]
+    fn test_sha3_padding_matches_reference() {
+        // Same permutation with SHA3 domain padding, against FIPS 202 output;
+        // covers an input that fills a block exactly.
+        assert_eq!(
+            sponge(&pattern::<136>(), 0x06),
+            hex32("1c33504292f84699c382b4e53645f483c6cc5cf0fdb78ff87a1c4d12c26915a7")
+        );
+        assert_eq!(
+            sponge(&pattern::<300>(), 0x06),
+            hex32("c87546fd20d13b902ade349f6e67c3b1085d6a746ae0e9a01dfa62c26297b2a7")
+        );
+    }
+}
@@ -19,26 +19,138 @@ pub fn encode_to_slice(src: &[u8], dst_hex: &mut [u8], lowercase: bool) -> Resul
     if dst_hex.len() < out_len {
         return Err(Error::OutputTooSmall);
     }
+    #[cfg(all(feature = "simd", not(feature = "forbid-unsafe")))]
+    {
+        crate::simd::encode_to_slice_simd(src, &mut dst_hex[..out_len], lowercase);
+    }
+    #[cfg(any(not(feature = "simd"), feature = "forbid-unsafe"))]
+    {
+        encode_scalar(src, &mut dst_hex[..out_len], lowercase);
+    }
+    Ok(out_len)
+}
 
-    let alphabet = if lowercase {
-        b"0123456789abcdef"
+/// Encode a fixed-size array into a `[u8; M]` of hex, where `M == 2 * N`
+/// (checked at compile time).
+///
+/// The common crypto sizes (16, 20, 32 and 64 bytes) use fully unrolled
+/// kernels with no scalar tail; other sizes go through [`encode_to_slice`].
+/// As with [`decode_to_array`](crate::decode_to_array), the unrolled kernels
+/// use the baseline SSE2 / NEON chunk whatever the reported backend.
+///
+/// # Examples
+/// ```
+/// use fast_hex_lite::encode_to_array;
+///
+/// let hex: [u8; 8] = encode_to_array(&[0xde, 0xad, 0xbe, 0xef], true);
+/// assert_eq!(&hex, b"deadbeef");
+/// ```
+pub fn encode_to_array<const N: usize, const M: usize>(src: &[u8; N], lowercase: bool) -> [u8; M] {
+    const { assert!(M == 2 * N, "encode_to_array: M must be 2 * N") };
+
+    let mut out = [0u8; M];
+    if crate::decode::has_fixed_kernel(N) {
+        encode_fixed(src, &mut out, lowercase);
     } else {
-        b"0123456789ABCDEF"
-    };
-
-    // SAFETY-FREE hot loop:
-    // - dst_hex length already validated
-    // - we write exactly 2 bytes per input byte
-    for (byte, out_pair) in src
-        .iter()
-        .copied()
-        .zip(dst_hex[..out_len].chunks_exact_mut(2))
+        // infallible because the output is exactly 2 * N bytes
+        let _ = encode_to_slice(src, &mut out, lowercase);
+    }
+    out
+}
+
+#[inline]
+fn encode_fixed(src: &[u8], dst_hex: &mut [u8], lowercase: bool) {
+    #[cfg(all(feature = "simd", not(feature = "forbid-unsafe")))]
+    {
+        crate::simd::encode_fixed_base(src, dst_hex, lowercase);
+    }
+    #[cfg(any(not(feature = "simd"), feature = "forbid-unsafe"))]
     {
-        out_pair[0] = alphabet[(byte >> 4) as usize];
-        out_pair[1] = alphabet[(byte & 0x0f) as usize];
+        // Branch-free already; a constant length lets it unroll fully.
+        encode_scalar(src, dst_hex, lowercase);
     }
+}
 
-    Ok(out_len)
+// ── Scalar encoder ─────────────────────────────────────────────────────────
+
+/// Scalar encoder: one 2-byte pair-table load and store per input byte by
+/// default, or table-free SWAR (4 input bytes per step) with `small-tables`.
+#[inline]
+pub(crate) fn encode_scalar(src: &[u8], dst_hex: &mut [u8], lowercase: bool) {
+    // `dst_hex` is already sized-checked by the caller.
+    #[cfg(not(feature = "small-tables"))]
+    {
+        let table = if lowercase {
+            &HEXPAIR_LOWER
+        } else {
+            &HEXPAIR_UPPER
+        };
+        let (pairs, _) = dst_hex.as_chunks_mut::<2>();
+        for (&byte, pair) in src.iter().zip(pairs) {
+            *pair = table[usize::from(byte)];
+        }
+    }
+    #[cfg(feature = "small-tables")]
+    {
+        let letter_off = if lowercase {
+            b'a' - b'9' - 1
+        } else {
+            b'A' - b'9' - 1
+        };
+        let (words, tail) = src.as_chunks::<4>();
+        let (out_words, out_tail) = dst_hex.split_at_mut(words.len() * 8);
+        for (word, out) in words.iter().zip(out_words.as_chunks_mut::<8>().0) {
+            *out = swar_encode4(u32::from_le_bytes(*word), letter_off).to_le_bytes();
+        }
+        if !tail.is_empty() {
+            // Zero-padded final word; only the real pairs are stored.
+            let mut word = [0u8; 4];
+            word[..tail.len()].copy_from_slice(tail);
+            let ascii = swar_encode4(u32::from_le_bytes(word), letter_off).to_le_bytes();
+            out_tail[..tail.len() * 2].copy_from_slice(&ascii[..tail.len() * 2]);
+        }
+    }
+}
+
+// 256-entry tables of ASCII pairs, 512 bytes per case: small enough to stay
+// in L1 next to the caller's buffers.
+#[cfg(not(feature = "small-tables"))]
+static HEXPAIR_LOWER: [[u8; 2]; 256] = make_hexpair_table(b"0123456789abcdef");
+#[cfg(not(feature = "small-tables"))]
+static HEXPAIR_UPPER: [[u8; 2]; 256] = make_hexpair_table(b"0123456789ABCDEF");
+
+#[cfg(not(feature = "small-tables"))]
+const fn make_hexpair_table(alphabet: &[u8; 16]) -> [[u8; 2]; 256] {
+    let mut t = [[0u8; 2]; 256];
+    let mut b = 0usize;
+    while b < 256 {
+        t[b] = [alphabet[b >> 4], alphabet[b & 0x0F]];
+        b += 1;
+    }
+    t
+}
+
+/// SWAR encode of 4 bytes (little-endian in `word`) into 8 ASCII hex chars
+/// (little-endian in the result). `letter_off` is the distance from `'9' + 1`
+/// to the first letter of the wanted case.
+#[cfg(feature = "small-tables")]
+#[inline]
+fn swar_encode4(word: u32, letter_off: u8) -> u64 {
+    const ONES: u64 = 0x0101_0101_0101_0101;
+    const LOW_NIBBLES: u64 = 0x000F_000F_000F_000F;
+
+    // Spread each input byte into its own 16-bit lane.
+    let x = u64::from(word);
+    let x = (x | (x << 16)) & 0x0000_FFFF_0000_FFFF;
+    let x = (x | (x << 8)) & 0x00FF_00FF_00FF_00FF;
+
+    // High nibble in the lane's first byte, low nibble in its second, so
+    // the output comes out in memory order.
+    let n = ((x >> 4) & LOW_NIBBLES) | ((x & LOW_NIBBLES) << 8);
+
+    // Bit 4 of `n + 6` is set exactly for nibbles >= 10; no byte carries.
+    let letters = ((n + ONES * 6) >> 4) & ONES;
+    n + ONES * u64::from(b'0') + letters * u64::from(letter_off)
 }
 
 /// Encode into a newly allocated `String`.
@@ -183,6 +295,30 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_encode_scalar_every_length_and_byte() {
+        // Covers the word loop and every tail length of the SWAR variant.
+        let src: Vec<u8> = (0u8..=255).rev().chain(0u8..=255).collect();
+        for len in 0..=src.len() {
+            for lowercase in [true, false] {
+                let mut out = std::vec![0xAAu8; len * 2 + 3];
+                encode_scalar(&src[..len], &mut out[..len * 2], lowercase);
+                let expected: String = src[..len]
+                    .iter()
+                    .map(|b| {
+                        if lowercase {
+                            std::format!("{b:02x}")
+                        } else {
+                            std::format!("{b:02X}")
+                        }
+                    })
+                    .collect();
+                assert_eq!(&out[..len * 2], expected.as_bytes(), "len={len}");
+                assert_eq!(&out[len * 2..], &[0xAA; 3], "len={len}");
+            }
+        }
+    }
+
     #[cfg(feature = "std")]
     #[test]
     fn test_encode_to_string_lowercase() {
@@ -201,6 +337,27 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_encode_to_array_matches_encode_to_slice() {
+        fn check<const N: usize, const M: usize>() {
+            let src: [u8; N] = core::array::from_fn(|i| u8::try_from(i * 37 % 256).unwrap());
+            for lowercase in [true, false] {
+                let mut expected = [0u8; M];
+                encode_scalar(&src, &mut expected, lowercase);
+                assert_eq!(encode_to_array::<N, M>(&src, lowercase), expected, "N={N}");
+            }
+        }
+        // Unrolled sizes.
+        check::<16, 32>();
+        check::<20, 40>();
+        check::<32, 64>();
+        check::<64, 128>();
+        // Generic path.
+        check::<0, 0>();
+        check::<3, 6>();
+        check::<21, 42>();
+    }
+
     #[cfg(feature = "std")]
     #[test]
     fn test_encode_to_string_empty() {
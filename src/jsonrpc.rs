@@ -0,0 +1,394 @@
+//! Ethereum JSON-RPC hex encodings: `DATA` and `QUANTITY`.
+//!
+//! - **DATA** is unformatted bytes: `0x` plus two digits per byte, any
+//!   length (`0x` alone is empty data).
+//! - **QUANTITY** is an integer: `0x` plus the shortest hex form, so no
+//!   leading zeros, `0x0` for zero, and an odd number of digits is normal
+//!   (`0x400`).
+//!
+//! The decoders here accept exactly these forms; the encoders always write
+//! the canonical one (lowercase digits).
+
+use crate::decode::decode_validated;
+use crate::prefix::has_prefix;
+use crate::validate::validate_digits;
+use crate::{decode_to_slice_prefixed, encode_to_slice, encode_to_slice_prefixed, Error};
+use crate::{encoded_len, PrefixPolicy};
+
+/// Maximum QUANTITY length in bytes for an `n`-byte integer: `0x` plus two
+/// digits per byte (at least `0x0`).
+///
+/// # Examples
+/// ```
+/// use fast_hex_lite::jsonrpc::quantity_max_len;
+///
+/// assert_eq!(quantity_max_len(8), 18); // u64: 0xffffffffffffffff
+/// assert_eq!(quantity_max_len(32), 66); // U256
+/// assert_eq!(quantity_max_len(0), 3); // 0x0
+/// ```
+#[inline]
+pub const fn quantity_max_len(n: usize) -> usize {
+    if n == 0 {
+        3
+    } else {
+        2 + encoded_len(n)
+    }
+}
+
+/// Encode `src` as DATA: `0x` and lowercase hex. The output length is
+/// [`encoded_len_prefixed`](crate::encoded_len_prefixed).
+///
+/// Returns the number of bytes written.
+///
+/// # Errors
+///
+/// Returns [`Error::OutputTooSmall`] if `dst` is not large enough.
+///
+/// # Examples
+/// ```
+/// use fast_hex_lite::jsonrpc::encode_data;
+///
+/// let mut out = [0u8; 8];
+/// let n = encode_data(&[0x41, 0x00, 0x01], &mut out).unwrap();
+/// assert_eq!(&out[..n], b"0x410001");
+/// ```
+#[inline]
+pub fn encode_data(src: &[u8], dst: &mut [u8]) -> Result<usize, Error> {
+    encode_to_slice_prefixed(src, dst, true)
+}
+
+/// Decode DATA: `0x` followed by an even number of hex digits. `0x` alone
+/// is valid and decodes to nothing.
+///
+/// Returns the number of bytes written.
+///
+/// # Errors
+///
+/// - [`Error::MissingPrefix`] without `0x`.
+/// - Otherwise the errors of [`decode_to_slice`](crate::decode_to_slice),
+///   with indices into `src` including the prefix.
+///
+/// # Examples
+/// ```
+/// use fast_hex_lite::{jsonrpc::decode_data, Error};
+///
+/// let mut out = [0u8; 3];
+/// assert_eq!(decode_data(b"0x410001", &mut out), Ok(3));
+/// assert_eq!(decode_data(b"0x", &mut out), Ok(0));
+/// assert_eq!(decode_data(b"0x400", &mut out), Err(Error::OddLength));
+/// assert_eq!(decode_data(b"410001", &mut out), Err(Error::MissingPrefix));
+/// ```
+#[inline]
+pub fn decode_data(src: &[u8], dst: &mut [u8]) -> Result<usize, Error> {
+    decode_to_slice_prefixed(src, dst, PrefixPolicy::Required)
+}
+
+/// Encode a big-endian unsigned integer (e.g. a 32-byte U256) as a
+/// QUANTITY: `0x` and the shortest lowercase hex, `0x0` for zero.
+///
+/// At most [`quantity_max_len`]`(be.len())` bytes are written.
+///
+/// Returns the number of bytes written.
+///
+/// # Errors
+///
+/// Returns [`Error::OutputTooSmall`] if `dst` is not large enough for this
+/// value.
+///
+/// # Examples
+/// ```
+/// use fast_hex_lite::jsonrpc::encode_quantity_bytes;
+///
+/// let mut out = [0u8; 66];
+/// let mut value = [0u8; 32];
+/// value[30..].copy_from_slice(&[0x04, 0x00]);
+/// let n = encode_quantity_bytes(&value, &mut out).unwrap();
+/// assert_eq!(&out[..n], b"0x400");
+/// ```
+pub fn encode_quantity_bytes(be: &[u8], dst: &mut [u8]) -> Result<usize, Error> {
+    let Some(first) = be.iter().position(|&b| b != 0) else {
+        return write_all(dst, b"0x0");
+    };
+    let value = &be[first..];
+
+    // The top byte may need only one digit; the rest take two each.
+    let top_digits = if value[0] < 0x10 { 1 } else { 2 };
+    let out_len = 2 + top_digits + encoded_len(value.len() - 1);
+    if dst.len() < out_len {
+        return Err(Error::OutputTooSmall);
+    }
+
+    let mut top = [0u8; 2];
+    encode_to_slice(&value[..1], &mut top, true)?;
+    dst[..2].copy_from_slice(b"0x");
+    dst[2..2 + top_digits].copy_from_slice(&top[2 - top_digits..]);
+    encode_to_slice(&value[1..], &mut dst[2 + top_digits..out_len], true)?;
+    Ok(out_len)
+}
+
+/// Encode a `u64` as a QUANTITY; at most 18 bytes are written.
+///
+/// Returns the number of bytes written.
+///
+/// # Errors
+///
+/// Returns [`Error::OutputTooSmall`] if `dst` is not large enough for this
+/// value.
+///
+/// # Examples
+/// ```
+/// use fast_hex_lite::jsonrpc::encode_quantity_u64;
+///
+/// let mut out = [0u8; 18];
+/// let n = encode_quantity_u64(1024, &mut out).unwrap();
+/// assert_eq!(&out[..n], b"0x400");
+/// let n = encode_quantity_u64(0, &mut out).unwrap();
+/// assert_eq!(&out[..n], b"0x0");
+/// ```
+#[inline]
+pub fn encode_quantity_u64(value: u64, dst: &mut [u8]) -> Result<usize, Error> {
+    encode_quantity_bytes(&value.to_be_bytes(), dst)
+}
+
+/// Encode a `u128` as a QUANTITY; at most 34 bytes are written.
+///
+/// Returns the number of bytes written.
+///
+/// # Errors
+///
+/// Returns [`Error::OutputTooSmall`] if `dst` is not large enough for this
+/// value.
+#[inline]
+pub fn encode_quantity_u128(value: u128, dst: &mut [u8]) -> Result<usize, Error> {
+    encode_quantity_bytes(&value.to_be_bytes(), dst)
+}
+
+/// Decode a QUANTITY into an `N`-byte big-endian integer (e.g. `N = 32`
+/// for U256), zero-extended on the left.
+///
+/// # Errors
+///
+/// - [`Error::MissingPrefix`] without `0x`.
+/// - [`Error::EmptyQuantity`] for `0x` with no digits.
+/// - [`Error::LeadingZero`] for a leading `0` digit other than `0x0`.
+/// - [`Error::InvalidByte`] for a non-hex digit (index into `src`).
+/// - [`Error::OutputTooSmall`] if the value does not fit in `N` bytes.
+///
+/// # Examples
+/// ```
+/// use fast_hex_lite::{jsonrpc::decode_quantity_bytes, Error};
+///
+/// let v: [u8; 32] = decode_quantity_bytes(b"0x400").unwrap();
+/// assert_eq!(v[30..], [0x04, 0x00]);
+/// assert_eq!(decode_quantity_bytes::<32>(b"0x0400"), Err(Error::LeadingZero));
+/// assert_eq!(decode_quantity_bytes::<32>(b"0x"), Err(Error::EmptyQuantity));
+/// assert_eq!(decode_quantity_bytes::<32>(b"400"), Err(Error::MissingPrefix));
+/// ```
+pub fn decode_quantity_bytes<const N: usize>(src: &[u8]) -> Result<[u8; N], Error> {
+    if !has_prefix(src) {
+        return Err(Error::MissingPrefix);
+    }
+    let digits = &src[2..];
+    match digits {
+        [] => return Err(Error::EmptyQuantity),
+        // Zero fits any width, even `N = 0`.
+        [b'0'] => return Ok([0u8; N]),
+        [b'0', _, ..] => return Err(Error::LeadingZero),
+        _ => {}
+    }
+    validate_digits(digits, 2)?;
+
+    let len = digits.len().div_ceil(2);
+    if len > N {
+        return Err(Error::OutputTooSmall);
+    }
+
+    let mut out = [0u8; N];
+    let value = &mut out[N - len..];
+    // An odd digit count leaves the top byte with a single digit.
+    let (top, rest) = digits.split_at(digits.len() % 2);
+    let (top_byte, rest_bytes) = value.split_at_mut(top.len());
+    if let [d] = top {
+        decode_validated(&[b'0', *d], top_byte);
+    }
+    decode_validated(rest, rest_bytes);
+    Ok(out)
+}
+
+/// Decode a QUANTITY into a `u64`.
+///
+/// # Errors
+///
+/// See [`decode_quantity_bytes`]; [`Error::OutputTooSmall`] if the value
+/// exceeds `u64::MAX`.
+///
+/// # Examples
+/// ```
+/// use fast_hex_lite::jsonrpc::decode_quantity_u64;
+///
+/// assert_eq!(decode_quantity_u64(b"0x400"), Ok(1024));
+/// assert_eq!(decode_quantity_u64(b"0x0"), Ok(0));
+/// ```
+#[inline]
+pub fn decode_quantity_u64(src: &[u8]) -> Result<u64, Error> {
+    decode_quantity_bytes(src).map(u64::from_be_bytes)
+}
+
+/// Decode a QUANTITY into a `u128`.
+///
+/// # Errors
+///
+/// See [`decode_quantity_bytes`]; [`Error::OutputTooSmall`] if the value
+/// exceeds `u128::MAX`.
+#[inline]
+pub fn decode_quantity_u128(src: &[u8]) -> Result<u128, Error> {
+    decode_quantity_bytes(src).map(u128::from_be_bytes)
+}
+
+fn write_all(dst: &mut [u8], s: &[u8]) -> Result<usize, Error> {
+    let out = dst.get_mut(..s.len()).ok_or(Error::OutputTooSmall)?;
+    out.copy_from_slice(s);
+    Ok(s.len())
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+    use super::*;
+    use std::prelude::v1::*;
+
+    fn quantity_u128(v: u128) -> Vec<u8> {
+        let mut out = [0u8; 34];
+        let n = encode_quantity_u128(v, &mut out).unwrap();
+        out[..n].to_vec()
+    }
+
+    #[test]
+    fn test_quantity_spec_examples() {
+        // From the Ethereum JSON-RPC specification.
+        assert_eq!(quantity_u128(0x41), b"0x41");
+        assert_eq!(quantity_u128(1024), b"0x400");
+        assert_eq!(quantity_u128(0), b"0x0");
+
+        assert_eq!(decode_quantity_u64(b"0x41"), Ok(0x41));
+        assert_eq!(decode_quantity_u64(b"0x400"), Ok(1024));
+        assert_eq!(decode_quantity_u64(b"0x0"), Ok(0));
+        assert_eq!(decode_quantity_u64(b"0x"), Err(Error::EmptyQuantity));
+        assert_eq!(decode_quantity_u64(b"0x0400"), Err(Error::LeadingZero));
+        assert_eq!(decode_quantity_u64(b"0x00"), Err(Error::LeadingZero));
+        assert_eq!(decode_quantity_u64(b"ff"), Err(Error::MissingPrefix));
+        assert_eq!(decode_quantity_u64(b""), Err(Error::MissingPrefix));
+    }
+
+    #[test]
+    fn test_quantity_roundtrip_matches_format() {
+        let mut values = vec![
+            0u128,
+            1,
+            0xf,
+            0x10,
+            0xff,
+            0x100,
+            u128::from(u64::MAX),
+            u128::MAX,
+        ];
+        values.extend((0..128).map(|s| 1u128 << s));
+        values.extend((1..128).map(|s| (1u128 << s) - 1));
+        for v in values {
+            let text = quantity_u128(v);
+            assert_eq!(text, std::format!("{v:#x}").into_bytes());
+            assert_eq!(decode_quantity_u128(&text), Ok(v));
+            if let Ok(small) = u64::try_from(v) {
+                let mut out = [0u8; 18];
+                let n = encode_quantity_u64(small, &mut out).unwrap();
+                assert_eq!(out[..n], text);
+                assert_eq!(decode_quantity_u64(&text), Ok(small));
+            } else {
+                assert_eq!(decode_quantity_u64(&text), Err(Error::OutputTooSmall));
+            }
+        }
+        // Uppercase digits and `0X` are accepted on input.
+        assert_eq!(decode_quantity_u64(b"0XDEADbeef"), Ok(0xdead_beef));
+    }
+
+    #[test]
+    fn test_quantity_u256() {
+        let mut value = [0u8; 32];
+        for (i, b) in value.iter_mut().enumerate().skip(1) {
+            *b = u8::try_from(i * 37 % 256).unwrap();
+        }
+        let mut out = [0u8; 66];
+        let n = encode_quantity_bytes(&value, &mut out).unwrap();
+        // Leading zero byte dropped, top byte 0x25 written in full.
+        assert_eq!(n, 64);
+        assert_eq!(decode_quantity_bytes::<32>(&out[..n]), Ok(value));
+        assert_eq!(
+            decode_quantity_bytes::<31>(&out[..n]),
+            Ok(<[u8; 31]>::try_from(&value[1..]).unwrap())
+        );
+        assert_eq!(
+            decode_quantity_bytes::<30>(&out[..n]),
+            Err(Error::OutputTooSmall)
+        );
+
+        assert_eq!(encode_quantity_bytes(&[0u8; 32], &mut out), Ok(3));
+        assert_eq!(&out[..3], b"0x0");
+        assert_eq!(encode_quantity_bytes(&[], &mut out), Ok(3));
+        assert_eq!(decode_quantity_bytes::<0>(b"0x0"), Ok([]));
+    }
+
+    #[test]
+    fn test_quantity_errors() {
+        assert_eq!(
+            decode_quantity_u64(b"0x4g0"),
+            Err(Error::InvalidByte {
+                index: 3,
+                byte: b'g'
+            })
+        );
+        // The leading zero is reported before later problems.
+        assert_eq!(decode_quantity_u64(b"0x0g"), Err(Error::LeadingZero));
+        assert_eq!(
+            decode_quantity_u64(b"0x11112222333344445"),
+            Err(Error::OutputTooSmall)
+        );
+
+        let mut out = [0xAAu8; 4];
+        assert_eq!(
+            encode_quantity_u64(0x1000, &mut out),
+            Err(Error::OutputTooSmall)
+        );
+        assert_eq!(out, [0xAA; 4]);
+        assert_eq!(
+            encode_quantity_u64(0, &mut out[..2]),
+            Err(Error::OutputTooSmall)
+        );
+        assert_eq!(
+            encode_quantity_u64(0xfff, &mut out[..]),
+            Err(Error::OutputTooSmall)
+        );
+        assert_eq!(encode_quantity_u64(0xff, &mut out[..]), Ok(4));
+    }
+
+    #[test]
+    fn test_data() {
+        let mut out = [0u8; 16];
+        assert_eq!(encode_data(&[], &mut out), Ok(2));
+        assert_eq!(&out[..2], b"0x");
+        assert_eq!(encode_data(&[0x00, 0x0f], &mut out), Ok(6));
+        assert_eq!(&out[..6], b"0x000f");
+
+        let mut dst = [0u8; 4];
+        assert_eq!(decode_data(b"0x000f", &mut dst), Ok(2));
+        assert_eq!(dst[..2], [0x00, 0x0f]);
+        assert_eq!(decode_data(b"0x0", &mut dst), Err(Error::OddLength));
+        assert_eq!(
+            decode_data(b"0x00zz", &mut dst),
+            Err(Error::InvalidByte {
+                index: 4,
+                byte: b'z'
+            })
+        );
+        assert_eq!(decode_data(b"", &mut dst), Err(Error::MissingPrefix));
+    }
+}
@@ -2,12 +2,34 @@
 //!
 //! - `no_std` by default (scalar path)
 //! - Optional `std` support
-//! - Optional `simd` accelerated decode/validate on supported targets
+//! - Optional `simd` accelerated decode/validate/encode on supported targets
 //!
 //! ## API
 //!
 //! - Decode: [`decode_to_slice`], [`decode_to_array`], [`decode_in_place`]
-//! - Encode: [`encode_to_slice`]
+//! - Single-pass decode (may partially write on error): [`decode_to_slice_fused`]
+//! - Trusted input, no validation: [`decode_to_slice_unchecked`]
+//! - Odd digit counts (`abc` as `0abc` or `abc0`): [`decode_to_slice_odd`],
+//!   [`decode_in_place_odd`], [`decoded_len_odd`]
+//! - Case-strict decode (lowercase / uppercase only): [`decode_to_slice_case`],
+//!   [`decode_in_place_case`], [`validate_case`]
+//! - Encode: [`encode_to_slice`], [`encode_to_array`]
+//! - `0x` prefix: [`decode_to_slice_prefixed`], [`decode_to_array_prefixed`],
+//!   [`encode_to_slice_prefixed`], [`encoded_len_prefixed`]
+//! - Separated encode (`de:ad:be:ef`): [`encode_to_slice_separated`], [`encoded_len_separated`]
+//! - Separator-tolerant decode: [`decode_to_slice_separated`], [`decoded_len_separated`]
+//! - Whitespace-insensitive decode (errors with line/column): [`decode_to_slice_ws`],
+//!   [`decode_in_place_ws`], [`decoded_len_ws`]
+//! - Batches of fixed-size items: [`decode_batch`], [`encode_batch`]
+//! - Validate: [`validate`], [`classify`]
+//! - Multi-threaded (`std`): `par_decode_to_slice`, `par_encode_to_slice`
+//! - Backend introspection / override: [`backend`], [`set_backend`]
+//! - Non-temporal stores for huge outputs: [`set_stream_threshold`]
+//! - Constant-time (secret material): [`ct::encode_to_slice_ct`], [`ct::decode_to_slice_ct`]
+//! - Ethereum checksummed addresses (EIP-55 / EIP-1191): [`eip55::encode_to_array`],
+//!   [`eip55::decode_to_array`], [`eip55::verify`]
+//! - Ethereum JSON-RPC DATA / QUANTITY: [`jsonrpc::decode_data`],
+//!   [`jsonrpc::encode_quantity_u64`], [`jsonrpc::decode_quantity_bytes`], …
 //!
 //! ## Examples
 //!
@@ -34,6 +56,7 @@
 //! ```
 
 #![cfg_attr(not(feature = "std"), no_std)]
+#![cfg_attr(feature = "forbid-unsafe", forbid(unsafe_code))]
 #![warn(missing_docs, clippy::all, clippy::pedantic)]
 #![allow(
     clippy::module_name_repetitions,
@@ -42,21 +65,56 @@
     clippy::must_use_candidate
 )]
 
+mod backend;
+mod batch;
+pub mod ct;
 mod decode;
+pub mod eip55;
 mod encode;
+pub mod jsonrpc;
+mod prefix;
+mod separated;
+mod validate;
+mod whitespace;
 
-#[cfg(feature = "simd")]
+#[cfg(feature = "std")]
+mod par;
+#[cfg(all(feature = "simd", not(feature = "forbid-unsafe")))]
 mod simd;
 
-pub use decode::{decode_in_place, decode_to_array, decode_to_slice, decoded_len};
-pub use encode::{encode_to_slice, encoded_len};
+pub use backend::{backend, set_backend, set_stream_threshold, stream_threshold, Backend};
+pub use batch::{decode_batch, encode_batch, BatchError};
+pub use decode::{
+    decode_in_place, decode_in_place_case, decode_in_place_odd, decode_to_array, decode_to_slice,
+    decode_to_slice_case, decode_to_slice_fused, decode_to_slice_odd, decode_to_slice_unchecked,
+    decoded_len, decoded_len_odd, OddLengthPolicy,
+};
+pub use encode::{encode_to_array, encode_to_slice, encoded_len};
+pub use prefix::{
+    decode_to_array_prefixed, decode_to_slice_prefixed, encode_to_slice_prefixed,
+    encoded_len_prefixed, PrefixPolicy,
+};
+pub use separated::{
+    decode_to_slice_separated, decoded_len_separated, encode_to_slice_separated,
+    encoded_len_separated, SeparatorPolicy,
+};
+pub use validate::{classify, validate, validate_case, CaseClass, CasePolicy, Classification};
+pub use whitespace::{decode_in_place_ws, decode_to_slice_ws, decoded_len_ws};
 
 // `encode_to_string` requires allocation (String), so it is only available with `std`.
 #[cfg(feature = "std")]
 pub use encode::encode_to_string;
 
+// Multi-threaded codecs for very large buffers; need `std::thread`.
+#[cfg(feature = "std")]
+pub use par::{par_decode_to_slice, par_encode_to_slice};
+
 /// Errors that can occur during hex encoding or decoding.
+///
+/// New variants may be added in minor releases, so a `match` on `Error`
+/// needs a wildcard arm.
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum Error {
     /// The input length is odd; hex strings must have even number of bytes.
     OddLength,
@@ -69,6 +127,38 @@ pub enum Error {
         /// The offending byte value.
         byte: u8,
     },
+    /// The input contains at least one invalid byte. Returned by the
+    /// constant-time decoder, which does not reveal where.
+    InvalidHex,
+    /// Like [`Error::InvalidByte`], with the byte's position in text terms.
+    /// Returned by the whitespace-insensitive decoders, whose input is
+    /// usually multi-line.
+    InvalidByteAt {
+        /// Zero-based index into the source slice.
+        index: usize,
+        /// The offending byte value.
+        byte: u8,
+        /// One-based line number (lines end at `\n`).
+        line: usize,
+        /// One-based column, in bytes from the start of the line.
+        column: usize,
+    },
+    /// The input has no `0x` / `0X` prefix but the decoder was asked to
+    /// require one ([`PrefixPolicy::Required`]).
+    MissingPrefix,
+    /// A hex letter of the wrong case for a case-strict decoder (see
+    /// [`CasePolicy`]), e.g. `A` when only lowercase is accepted.
+    WrongCase {
+        /// Zero-based index into the source slice.
+        index: usize,
+        /// The offending letter.
+        byte: u8,
+    },
+    /// A JSON-RPC QUANTITY has a leading zero digit (`0x0400`); only `0x0`
+    /// itself may start with `0`. See [`jsonrpc`].
+    LeadingZero,
+    /// A JSON-RPC QUANTITY is `0x` with no digits; zero is written `0x0`.
+    EmptyQuantity,
 }
 
 impl core::fmt::Display for Error {
@@ -86,6 +176,32 @@ impl core::fmt::Display for Error {
                     index
                 )
             }
+            Error::InvalidHex => f.write_str("invalid hex input"),
+            Error::MissingPrefix => f.write_str("hex string is missing the 0x prefix"),
+            Error::WrongCase { index, byte } => write!(
+                f,
+                "hex letter '{}' at index {} has the wrong case",
+                *byte as char, index
+            ),
+            Error::LeadingZero => f.write_str("hex quantity has a leading zero"),
+            Error::EmptyQuantity => f.write_str("hex quantity has no digits"),
+            Error::InvalidByteAt {
+                index,
+                byte,
+                line,
+                column,
+            } => {
+                let b = *byte;
+                write!(
+                    f,
+                    "invalid hex byte 0x{:02x} ('{}') at line {}, column {} (index {})",
+                    b,
+                    if b.is_ascii_graphic() { b as char } else { '?' },
+                    line,
+                    column,
+                    index
+                )
+            }
         }
     }
 }
@@ -179,6 +295,38 @@ mod tests {
         assert_eq!(s, "hex string has odd length");
     }
 
+    #[test]
+    fn test_display_invalid_hex() {
+        let s = std::format!("{}", Error::InvalidHex);
+        assert_eq!(s, "invalid hex input");
+    }
+
+    #[test]
+    fn test_display_missing_prefix() {
+        let s = std::format!("{}", Error::MissingPrefix);
+        assert_eq!(s, "hex string is missing the 0x prefix");
+    }
+
+    #[test]
+    fn test_display_quantity_errors() {
+        let s = std::format!("{}", Error::LeadingZero);
+        assert_eq!(s, "hex quantity has a leading zero");
+        let s = std::format!("{}", Error::EmptyQuantity);
+        assert_eq!(s, "hex quantity has no digits");
+    }
+
+    #[test]
+    fn test_display_wrong_case() {
+        let s = std::format!(
+            "{}",
+            Error::WrongCase {
+                index: 4,
+                byte: b'B'
+            }
+        );
+        assert_eq!(s, "hex letter 'B' at index 4 has the wrong case");
+    }
+
     #[test]
     fn test_display_output_too_small() {
         let s = std::format!("{}", Error::OutputTooSmall);
@@ -228,6 +376,23 @@ mod tests {
         assert!(s.contains('?'));
     }
 
+    #[test]
+    fn test_display_invalid_byte_at() {
+        let s = std::format!(
+            "{}",
+            Error::InvalidByteAt {
+                index: 21,
+                byte: b'z',
+                line: 3,
+                column: 4
+            }
+        );
+        assert_eq!(
+            s,
+            "invalid hex byte 0x7a ('z') at line 3, column 4 (index 21)"
+        );
+    }
+
     #[test]
     fn test_display_invalid_byte_high_ascii() {
         let s = std::format!(
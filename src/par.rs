@@ -0,0 +1,289 @@
+//! Multi-threaded decode/encode for very large buffers (`std` only).
+//!
+//! Input is cut into cache-sized chunks that `std::thread::scope` workers pull
+//! from a shared queue; each chunk goes through the same single-threaded
+//! kernels. Decoding is two-phase like the rest of the crate: the whole input is
+//! validated in parallel first, so on error nothing is written and the
+//! reported index is the globally smallest one.
+
+use crate::decode::decode_validated;
+use crate::{decode_to_slice, decoded_len, encode_to_slice, encoded_len, validate, Error};
+
+use std::num::NonZeroUsize;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::thread;
+
+/// Hex chars per work item: 256 KiB of input, roughly one core's L2 share.
+const PAR_CHUNK_HEX: usize = 256 * 1024;
+
+/// Parallel variant of [`decode_to_slice`] for multi-megabyte inputs.
+///
+/// Inputs of a single chunk or less are decoded on the calling thread.
+/// Results and errors are identical to [`decode_to_slice`]: on error the
+/// reported index is the first invalid byte of the whole input and `dst` is
+/// left unchanged.
+///
+/// Returns the number of bytes written.
+///
+/// # Examples
+/// ```
+/// use fast_hex_lite::par_decode_to_slice;
+///
+/// let hex = "ab".repeat(1 << 20);
+/// let mut out = vec![0u8; 1 << 20];
+/// par_decode_to_slice(hex.as_bytes(), &mut out).unwrap();
+/// assert!(out.iter().all(|&b| b == 0xab));
+/// ```
+pub fn par_decode_to_slice(src_hex: &[u8], dst: &mut [u8]) -> Result<usize, Error> {
+    par_decode_with(src_hex, dst, PAR_CHUNK_HEX, worker_count())
+}
+
+/// Parallel variant of [`encode_to_slice`] for multi-megabyte inputs.
+///
+/// Inputs of a single chunk or less are encoded on the calling thread.
+///
+/// Returns the number of bytes written.
+///
+/// # Examples
+/// ```
+/// use fast_hex_lite::par_encode_to_slice;
+///
+/// let src = vec![0xabu8; 1 << 20];
+/// let mut out = vec![0u8; 2 << 20];
+/// par_encode_to_slice(&src, &mut out, true).unwrap();
+/// assert!(out.iter().all(|&c| c == b'a' || c == b'b'));
+/// ```
+pub fn par_encode_to_slice(
+    src: &[u8],
+    dst_hex: &mut [u8],
+    lowercase: bool,
+) -> Result<usize, Error> {
+    par_encode_with(src, dst_hex, lowercase, PAR_CHUNK_HEX / 2, worker_count())
+}
+
+fn worker_count() -> usize {
+    thread::available_parallelism().map_or(1, NonZeroUsize::get)
+}
+
+fn par_decode_with(
+    src_hex: &[u8],
+    dst: &mut [u8],
+    chunk_hex: usize,
+    workers: usize,
+) -> Result<usize, Error> {
+    debug_assert!(chunk_hex > 0 && chunk_hex.is_multiple_of(2));
+
+    let out_len = decoded_len(src_hex.len())?;
+    if dst.len() < out_len {
+        return Err(Error::OutputTooSmall);
+    }
+    let workers = workers.min(src_hex.len().div_ceil(chunk_hex));
+    if workers <= 1 {
+        return decode_to_slice(src_hex, dst);
+    }
+
+    // Pass 1: validate. Chunks are handed out in input order, so once an
+    // error is known every chunk still in the queue lies past it.
+    let first_bad = AtomicUsize::new(usize::MAX);
+    let queue = Mutex::new(src_hex.chunks(chunk_hex).enumerate());
+    let worst = thread::scope(|s| {
+        let handles: Vec<_> = (0..workers)
+            .map(|_| {
+                s.spawn(|| {
+                    let mut found: Option<Error> = None;
+                    loop {
+                        let Some((i, chunk)) = queue.lock().unwrap().next() else {
+                            break;
+                        };
+                        let base = i * chunk_hex;
+                        if base > first_bad.load(Ordering::Relaxed) {
+                            break;
+                        }
+                        if let Err(Error::InvalidByte { index, byte }) = validate(chunk) {
+                            let index = base + index;
+                            first_bad.fetch_min(index, Ordering::Relaxed);
+                            found = Some(Error::InvalidByte { index, byte });
+                            break;
+                        }
+                    }
+                    found
+                })
+            })
+            .collect();
+        handles
+            .into_iter()
+            .filter_map(|h| h.join().unwrap())
+            .min_by_key(|e| match e {
+                Error::InvalidByte { index, .. } => *index,
+                _ => usize::MAX,
+            })
+    });
+    if let Some(err) = worst {
+        return Err(err);
+    }
+
+    // Pass 2: decode. Input is known valid, so chunks skip validation.
+    let queue = Mutex::new(
+        src_hex
+            .chunks(chunk_hex)
+            .zip(dst[..out_len].chunks_mut(chunk_hex / 2)),
+    );
+    thread::scope(|s| {
+        for _ in 0..workers {
+            s.spawn(|| loop {
+                let Some((src, out)) = queue.lock().unwrap().next() else {
+                    break;
+                };
+                decode_validated(src, out);
+            });
+        }
+    });
+
+    Ok(out_len)
+}
+
+fn par_encode_with(
+    src: &[u8],
+    dst_hex: &mut [u8],
+    lowercase: bool,
+    chunk_in: usize,
+    workers: usize,
+) -> Result<usize, Error> {
+    debug_assert!(chunk_in > 0);
+
+    let out_len = encoded_len(src.len());
+    if dst_hex.len() < out_len {
+        return Err(Error::OutputTooSmall);
+    }
+    let workers = workers.min(src.len().div_ceil(chunk_in));
+    if workers <= 1 {
+        return encode_to_slice(src, dst_hex, lowercase);
+    }
+
+    let queue = Mutex::new(
+        src.chunks(chunk_in)
+            .zip(dst_hex[..out_len].chunks_mut(encoded_len(chunk_in))),
+    );
+    thread::scope(|s| {
+        for _ in 0..workers {
+            s.spawn(|| loop {
+                let Some((src, out)) = queue.lock().unwrap().next() else {
+                    break;
+                };
+                encode_to_slice(src, out, lowercase).expect("output chunk is pre-sized");
+            });
+        }
+    });
+
+    Ok(out_len)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Small chunks so the tests exercise many work items across threads.
+    const CHUNK: usize = 64;
+    const WORKERS: usize = 4;
+
+    fn sample(len: usize) -> Vec<u8> {
+        (0..len)
+            .map(|i| u8::try_from(i * 7 % 256).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_par_encode_matches_single_threaded() {
+        for len in [0, 1, 31, 32, 33, 1000, 4097] {
+            let src = sample(len);
+            for lowercase in [true, false] {
+                let mut par = vec![0u8; len * 2];
+                let mut seq = vec![0u8; len * 2];
+                assert_eq!(
+                    par_encode_with(&src, &mut par, lowercase, CHUNK / 2, WORKERS),
+                    Ok(len * 2)
+                );
+                encode_to_slice(&src, &mut seq, lowercase).unwrap();
+                assert_eq!(par, seq, "len={len}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_par_decode_roundtrip() {
+        for len in [0, 1, 31, 32, 33, 1000, 4097] {
+            let src = sample(len);
+            let mut hex = vec![0u8; len * 2];
+            encode_to_slice(&src, &mut hex, false).unwrap();
+            let mut out = vec![0u8; len];
+            assert_eq!(par_decode_with(&hex, &mut out, CHUNK, WORKERS), Ok(len));
+            assert_eq!(out, src, "len={len}");
+        }
+    }
+
+    #[test]
+    fn test_par_decode_reports_globally_smallest_index() {
+        let mut hex = vec![b'a'; 64 * 50];
+        // Bad bytes in several chunks; a later chunk may finish first.
+        hex[64 * 40 + 3] = b'x';
+        hex[64 * 7 + 10] = b'y';
+        hex[64 * 25] = b'z';
+        let mut out = vec![0x55u8; hex.len() / 2];
+        for _ in 0..20 {
+            assert_eq!(
+                par_decode_with(&hex, &mut out, CHUNK, WORKERS),
+                Err(Error::InvalidByte {
+                    index: 64 * 7 + 10,
+                    byte: b'y'
+                })
+            );
+        }
+        // No partial writes.
+        assert!(out.iter().all(|&b| b == 0x55));
+    }
+
+    #[test]
+    fn test_par_decode_error_in_every_position_matches_single_threaded() {
+        let src = sample(300);
+        let mut hex = vec![0u8; 600];
+        encode_to_slice(&src, &mut hex, true).unwrap();
+        for pos in (0..hex.len()).step_by(13) {
+            let mut bad = hex.clone();
+            bad[pos] = b'G';
+            let mut par = vec![0u8; 300];
+            let mut seq = vec![0u8; 300];
+            assert_eq!(
+                par_decode_with(&bad, &mut par, CHUNK, WORKERS),
+                decode_to_slice(&bad, &mut seq)
+            );
+        }
+    }
+
+    #[test]
+    fn test_par_length_errors() {
+        let mut out = vec![0u8; 10];
+        assert_eq!(
+            par_decode_with(b"abc", &mut out, CHUNK, WORKERS),
+            Err(Error::OddLength)
+        );
+        assert_eq!(
+            par_decode_with(&[b'0'; 40], &mut out[..5], CHUNK, WORKERS),
+            Err(Error::OutputTooSmall)
+        );
+        assert_eq!(
+            par_encode_with(&[0; 6], &mut out, true, CHUNK / 2, WORKERS),
+            Err(Error::OutputTooSmall)
+        );
+    }
+
+    #[test]
+    fn test_par_public_api_above_chunk_size() {
+        let src = sample(PAR_CHUNK_HEX * 3 / 2 + 17);
+        let mut hex = vec![0u8; src.len() * 2];
+        par_encode_to_slice(&src, &mut hex, true).unwrap();
+        let mut out = vec![0u8; src.len()];
+        par_decode_to_slice(&hex, &mut out).unwrap();
+        assert_eq!(out, src);
+    }
+}
@@ -0,0 +1,325 @@
+//! `0x` / `0X` prefix handling (Ethereum, Solidity, C literals).
+
+use crate::{decode_to_array, decode_to_slice, encode_to_slice, encoded_len, Error};
+
+/// Whether a `0x` / `0X` prefix is expected when decoding.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PrefixPolicy {
+    /// The input must start with `0x` or `0X`; otherwise
+    /// [`Error::MissingPrefix`].
+    Required,
+    /// A leading `0x` / `0X` is skipped if present.
+    Optional,
+    /// No prefix: the input is plain hex, as for
+    /// [`decode_to_slice`], so a prefix is reported as an invalid `x` at
+    /// index 1.
+    Forbidden,
+}
+
+/// `true` if `src` starts with `0x` or `0X`.
+#[inline]
+pub(crate) fn has_prefix(src: &[u8]) -> bool {
+    src.len() >= 2 && src[0] == b'0' && (src[1] | 0x20) == b'x'
+}
+
+/// Split off the prefix according to `policy`; returns the digits and their
+/// offset in `src`.
+fn strip(src: &[u8], policy: PrefixPolicy) -> Result<(&[u8], usize), Error> {
+    match policy {
+        PrefixPolicy::Forbidden => Ok((src, 0)),
+        _ if has_prefix(src) => Ok((&src[2..], 2)),
+        PrefixPolicy::Optional => Ok((src, 0)),
+        PrefixPolicy::Required => Err(Error::MissingPrefix),
+    }
+}
+
+/// Report `InvalidByte` indices against the original (prefixed) input.
+fn shift(e: Error, offset: usize) -> Error {
+    match e {
+        Error::InvalidByte { index, byte } => Error::InvalidByte {
+            index: index + offset,
+            byte,
+        },
+        e => e,
+    }
+}
+
+/// Decode hex that may start with `0x` / `0X`, as allowed by `policy`.
+///
+/// Apart from the prefix this is [`decode_to_slice`]: the digits are checked
+/// before anything is written, and [`Error::InvalidByte`] indices point into
+/// `src_hex` including the prefix.
+///
+/// Returns the number of bytes written.
+///
+/// # Errors
+///
+/// - [`Error::MissingPrefix`] if `policy` is `Required` and there is no
+///   prefix.
+/// - Otherwise the errors of [`decode_to_slice`] for the digits.
+///
+/// # Examples
+/// ```
+/// use fast_hex_lite::{decode_to_slice_prefixed, Error, PrefixPolicy};
+///
+/// let mut out = [0u8; 4];
+/// decode_to_slice_prefixed(b"0xdeadbeef", &mut out, PrefixPolicy::Required).unwrap();
+/// assert_eq!(out, [0xde, 0xad, 0xbe, 0xef]);
+///
+/// let err = decode_to_slice_prefixed(b"0xdeadbeeg", &mut out, PrefixPolicy::Optional);
+/// assert_eq!(err, Err(Error::InvalidByte { index: 9, byte: b'g' }));
+/// ```
+pub fn decode_to_slice_prefixed(
+    src_hex: &[u8],
+    dst: &mut [u8],
+    policy: PrefixPolicy,
+) -> Result<usize, Error> {
+    let (digits, offset) = strip(src_hex, policy)?;
+    decode_to_slice(digits, dst).map_err(|e| shift(e, offset))
+}
+
+/// Decode exactly `N` bytes from hex that may start with `0x` / `0X`, as
+/// allowed by `policy`: `2*N + 2` bytes with a prefix, `2*N` without.
+///
+/// Uses the same unrolled kernels as [`decode_to_array`].
+///
+/// # Errors
+///
+/// See [`decode_to_slice_prefixed`]; [`Error::OutputTooSmall`] if the digits
+/// do not decode to exactly `N` bytes.
+///
+/// # Examples
+/// ```
+/// use fast_hex_lite::{decode_to_array_prefixed, PrefixPolicy};
+///
+/// let addr: [u8; 20] = decode_to_array_prefixed(
+///     b"0x52908400098527886E0F7030069857D2E4169EE7",
+///     PrefixPolicy::Required,
+/// )
+/// .unwrap();
+/// assert_eq!(addr[0], 0x52);
+/// ```
+pub fn decode_to_array_prefixed<const N: usize>(
+    src_hex: &[u8],
+    policy: PrefixPolicy,
+) -> Result<[u8; N], Error> {
+    let (digits, offset) = strip(src_hex, policy)?;
+    decode_to_array(digits).map_err(|e| shift(e, offset))
+}
+
+/// Returns the output length for encoding `n` bytes as `0x`-prefixed hex.
+///
+/// # Examples
+/// ```
+/// use fast_hex_lite::encoded_len_prefixed;
+///
+/// assert_eq!(encoded_len_prefixed(4), 10); // 0xdeadbeef
+/// assert_eq!(encoded_len_prefixed(0), 2);
+/// ```
+#[inline]
+pub const fn encoded_len_prefixed(n: usize) -> usize {
+    2 + encoded_len(n)
+}
+
+/// Encode bytes as `0x`-prefixed hex. The prefix is always a lowercase `0x`;
+/// `lowercase` selects the case of the digits (`0xDEADBEEF`).
+///
+/// Returns the number of bytes written ([`encoded_len_prefixed`]).
+///
+/// # Errors
+///
+/// Returns [`Error::OutputTooSmall`] if `dst_hex` is not large enough.
+///
+/// # Examples
+/// ```
+/// use fast_hex_lite::encode_to_slice_prefixed;
+///
+/// let mut out = [0u8; 10];
+/// encode_to_slice_prefixed(&[0xde, 0xad, 0xbe, 0xef], &mut out, true).unwrap();
+/// assert_eq!(&out, b"0xdeadbeef");
+/// ```
+pub fn encode_to_slice_prefixed(
+    src: &[u8],
+    dst_hex: &mut [u8],
+    lowercase: bool,
+) -> Result<usize, Error> {
+    let out_len = encoded_len_prefixed(src.len());
+    if dst_hex.len() < out_len {
+        return Err(Error::OutputTooSmall);
+    }
+    dst_hex[..2].copy_from_slice(b"0x");
+    encode_to_slice(src, &mut dst_hex[2..out_len], lowercase)?;
+    Ok(out_len)
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+    use super::*;
+    use std::prelude::v1::*;
+
+    const ALL: [PrefixPolicy; 3] = [
+        PrefixPolicy::Required,
+        PrefixPolicy::Optional,
+        PrefixPolicy::Forbidden,
+    ];
+
+    fn decode(src: &[u8], policy: PrefixPolicy) -> Result<Vec<u8>, Error> {
+        let mut dst = vec![0u8; src.len() / 2];
+        let n = decode_to_slice_prefixed(src, &mut dst, policy)?;
+        dst.truncate(n);
+        Ok(dst)
+    }
+
+    #[test]
+    fn test_prefix_policies() {
+        for src in [&b"0xdeadbeef"[..], b"0XDEADBEEF"] {
+            assert_eq!(
+                decode(src, PrefixPolicy::Required).unwrap(),
+                [0xde, 0xad, 0xbe, 0xef]
+            );
+            assert_eq!(
+                decode(src, PrefixPolicy::Optional).unwrap(),
+                [0xde, 0xad, 0xbe, 0xef]
+            );
+            assert_eq!(
+                decode(src, PrefixPolicy::Forbidden),
+                Err(Error::InvalidByte {
+                    index: 1,
+                    byte: src[1]
+                })
+            );
+        }
+
+        assert_eq!(
+            decode(b"deadbeef", PrefixPolicy::Required),
+            Err(Error::MissingPrefix)
+        );
+        assert_eq!(
+            decode(b"dead", PrefixPolicy::Optional).unwrap(),
+            [0xde, 0xad]
+        );
+        assert_eq!(
+            decode(b"dead", PrefixPolicy::Forbidden).unwrap(),
+            [0xde, 0xad]
+        );
+
+        // "0x" alone is an empty value; a lone "0" is not a prefix.
+        assert_eq!(decode(b"0x", PrefixPolicy::Required).unwrap(), []);
+        assert_eq!(decode(b"0", PrefixPolicy::Optional), Err(Error::OddLength));
+        assert_eq!(
+            decode(b"", PrefixPolicy::Required),
+            Err(Error::MissingPrefix)
+        );
+        assert_eq!(decode(b"", PrefixPolicy::Optional).unwrap(), []);
+        // Only one prefix is stripped.
+        assert_eq!(
+            decode(b"0x0x", PrefixPolicy::Optional).unwrap_err(),
+            Error::InvalidByte {
+                index: 3,
+                byte: b'x'
+            }
+        );
+    }
+
+    #[test]
+    fn test_prefix_error_indices_point_into_original_input() {
+        let mut src = b"0x".to_vec();
+        src.extend(std::iter::repeat_n(b'a', 100));
+        for bad in [2, 3, 17, 50, 101] {
+            let mut s = src.clone();
+            s[bad] = b'?';
+            for policy in [PrefixPolicy::Required, PrefixPolicy::Optional] {
+                assert_eq!(
+                    decode(&s, policy),
+                    Err(Error::InvalidByte {
+                        index: bad,
+                        byte: b'?'
+                    })
+                );
+            }
+        }
+        assert_eq!(
+            decode(b"0xabc", PrefixPolicy::Required),
+            Err(Error::OddLength)
+        );
+        let mut dst = [0u8; 1];
+        assert_eq!(
+            decode_to_slice_prefixed(b"0xabcd", &mut dst, PrefixPolicy::Required),
+            Err(Error::OutputTooSmall)
+        );
+    }
+
+    #[test]
+    fn test_decode_to_array_prefixed() {
+        let bytes: Vec<u8> = (0..64u8).collect();
+        let mut hex = vec![0u8; encoded_len_prefixed(64)];
+        encode_to_slice_prefixed(&bytes, &mut hex, true).unwrap();
+
+        let arr: [u8; 32] = decode_to_array_prefixed(&hex[..66], PrefixPolicy::Required).unwrap();
+        assert_eq!(arr[..], bytes[..32]);
+        let arr: [u8; 3] = decode_to_array_prefixed(&hex[..8], PrefixPolicy::Optional).unwrap();
+        assert_eq!(arr[..], bytes[..3]);
+        let arr: [u8; 3] = decode_to_array_prefixed(&hex[2..8], PrefixPolicy::Optional).unwrap();
+        assert_eq!(arr[..], bytes[..3]);
+
+        // Length is checked against the digits, not the whole input.
+        assert_eq!(
+            decode_to_array_prefixed::<32>(&hex[..64], PrefixPolicy::Required),
+            Err(Error::OutputTooSmall)
+        );
+        assert_eq!(
+            decode_to_array_prefixed::<32>(&hex[2..66], PrefixPolicy::Required),
+            Err(Error::MissingPrefix)
+        );
+
+        // Fixed-kernel size: error index includes the prefix.
+        let mut bad = hex[..66].to_vec();
+        bad[40] = b'z';
+        assert_eq!(
+            decode_to_array_prefixed::<32>(&bad, PrefixPolicy::Optional),
+            Err(Error::InvalidByte {
+                index: 40,
+                byte: b'z'
+            })
+        );
+        assert_eq!(
+            decode_to_array_prefixed::<0>(b"", PrefixPolicy::Optional),
+            Ok([])
+        );
+    }
+
+    #[test]
+    fn test_encode_to_slice_prefixed() {
+        let mut out = [0u8; 10];
+        assert_eq!(
+            encode_to_slice_prefixed(&[0xde, 0xad, 0xbe, 0xef], &mut out, false),
+            Ok(10)
+        );
+        assert_eq!(&out, b"0xDEADBEEF");
+
+        let mut out = [0u8; 2];
+        assert_eq!(encode_to_slice_prefixed(&[], &mut out, true), Ok(2));
+        assert_eq!(&out, b"0x");
+
+        let mut out = [0xAAu8; 9];
+        assert_eq!(
+            encode_to_slice_prefixed(&[0xde, 0xad, 0xbe, 0xef], &mut out, true),
+            Err(Error::OutputTooSmall)
+        );
+        assert_eq!(out, [0xAA; 9]);
+
+        // Round trip through every policy that accepts a prefix.
+        let src: Vec<u8> = (0..=255u8).collect();
+        let mut hex = vec![0u8; encoded_len_prefixed(src.len())];
+        encode_to_slice_prefixed(&src, &mut hex, true).unwrap();
+        for policy in ALL {
+            let res = decode(&hex, policy);
+            if policy == PrefixPolicy::Forbidden {
+                assert!(res.is_err());
+            } else {
+                assert_eq!(res.unwrap(), src);
+            }
+        }
+    }
+}
@@ -0,0 +1,634 @@
+//! Separated hex encoding and decoding (`de:ad:be:ef`, `deadbeef 01020304`).
+
+use crate::decode::decode_validated;
+use crate::validate::validate_digits;
+use crate::{encode_to_slice, encoded_len, Error};
+
+/// Input bytes encoded per stack block when groups are small.
+const BLOCK_IN: usize = 128;
+
+/// Hex digits gathered per stack block when decoding.
+const GATHER_HEX: usize = 256;
+
+/// Where separators may appear when decoding separated hex.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SeparatorPolicy {
+    /// At most one separator between two bytes (`de:ad:be:ef`, `de:adbeef`);
+    /// none inside a byte or at either end.
+    BetweenBytes,
+    /// Exactly one separator after every `n` bytes and nowhere else, the
+    /// layout written by [`encode_to_slice_separated`] (`deadbeef 01020304`
+    /// for `Grouped(4)`). The last group may be shorter. `n` must be non-zero.
+    Grouped(usize),
+    /// Any number of separators anywhere, even inside a byte (`d e:a d`).
+    Anywhere,
+}
+
+/// Returns the output length for encoding `n` bytes in groups of `group`
+/// bytes joined by a `sep_len`-byte separator (none before the first group
+/// or after the last).
+///
+/// # Panics
+///
+/// Panics if `group` is 0.
+///
+/// # Examples
+/// ```
+/// use fast_hex_lite::encoded_len_separated;
+///
+/// assert_eq!(encoded_len_separated(4, 1, 1), 11); // de:ad:be:ef
+/// assert_eq!(encoded_len_separated(8, 1, 4), 17); // deadbeef 01020304
+/// assert_eq!(encoded_len_separated(0, 1, 1), 0);
+/// ```
+#[inline]
+pub const fn encoded_len_separated(n: usize, sep_len: usize, group: usize) -> usize {
+    assert!(group > 0, "encoded_len_separated: group must be non-zero");
+    if n == 0 {
+        0
+    } else {
+        encoded_len(n) + sep_len * ((n - 1) / group)
+    }
+}
+
+/// Encode bytes as hex with `sep` between every `group` bytes.
+///
+/// `sep` may be a single byte (`b":"`) or a longer string (`b" - "`); an
+/// empty `sep` gives the same output as [`encode_to_slice`]. Output length is
+/// [`encoded_len_separated`]. One-byte separators with a `group` of 1, 2, 4
+/// or 8 (MAC addresses, fingerprints, `xxd`-style dumps) use a dedicated SIMD
+/// kernel under the `simd` feature; other layouts encode in bulk and then
+/// place whole groups.
+///
+/// Returns the number of bytes written on success.
+///
+/// # Errors
+///
+/// Returns [`Error::OutputTooSmall`] if `dst` is not large enough.
+///
+/// # Panics
+///
+/// Panics if `group` is 0.
+///
+/// # Examples
+/// ```
+/// use fast_hex_lite::encode_to_slice_separated;
+///
+/// let mut out = [0u8; 11];
+/// encode_to_slice_separated(&[0xde, 0xad, 0xbe, 0xef], &mut out, b":", 1, true).unwrap();
+/// assert_eq!(&out, b"de:ad:be:ef");
+///
+/// let mut out = [0u8; 17];
+/// let src = [0xde, 0xad, 0xbe, 0xef, 1, 2, 3, 4];
+/// encode_to_slice_separated(&src, &mut out, b" ", 4, false).unwrap();
+/// assert_eq!(&out, b"DEADBEEF 01020304");
+/// ```
+pub fn encode_to_slice_separated(
+    src: &[u8],
+    dst: &mut [u8],
+    sep: &[u8],
+    group: usize,
+    lowercase: bool,
+) -> Result<usize, Error> {
+    let out_len = encoded_len_separated(src.len(), sep.len(), group);
+    if dst.len() < out_len {
+        return Err(Error::OutputTooSmall);
+    }
+    let dst = &mut dst[..out_len];
+    if sep.is_empty() {
+        return encode_to_slice(src, dst, lowercase);
+    }
+    let group = clamp_group(group, src.len());
+
+    // Whole groups done by the SIMD kernel; their trailing separators are
+    // already in place.
+    #[allow(unused_mut)]
+    let mut done = 0;
+    #[cfg(all(feature = "simd", not(feature = "forbid-unsafe")))]
+    if sep.len() == 1 {
+        done = crate::simd::encode_group_sep_simd(src, dst, sep[0], group, lowercase);
+    }
+    let out_done = done / group * (encoded_len(group) + sep.len());
+
+    encode_groups(&src[done..], &mut dst[out_done..], sep, group, lowercase);
+    Ok(out_len)
+}
+
+/// Layout pass: `dst` is exactly `encoded_len_separated(src.len(), ..)`.
+fn encode_groups(src: &[u8], dst: &mut [u8], sep: &[u8], group: usize, lowercase: bool) {
+    if src.is_empty() {
+        return;
+    }
+
+    // Every group but the last is followed by a separator, so those fill
+    // fixed `stride`-sized slots; the last group ends the output.
+    let stride = encoded_len(group) + sep.len();
+    let body_groups = (src.len() - 1) / group;
+    let (body, last) = src.split_at(body_groups * group);
+    let (dst_body, dst_last) = dst.split_at_mut(body_groups * stride);
+    // infallible because the range is exactly sized
+    let _ = encode_to_slice(last, dst_last, lowercase);
+
+    // Large groups are contiguous hex runs: encode each straight into place.
+    if group >= BLOCK_IN / 8 {
+        for (g, slot) in body
+            .chunks_exact(group)
+            .zip(dst_body.chunks_exact_mut(stride))
+        {
+            let (hex, sep_slot) = slot.split_at_mut(encoded_len(group));
+            let _ = encode_to_slice(g, hex, lowercase);
+            sep_slot.copy_from_slice(sep);
+        }
+        return;
+    }
+
+    // Small groups: bulk-encode a block of whole groups on the stack (SIMD
+    // when enabled), then copy it out group by group. Common sizes get a
+    // constant width so the copies compile to plain stores.
+    match group {
+        1 => scatter_groups::<1>(body, dst_body, sep, group, lowercase),
+        2 => scatter_groups::<2>(body, dst_body, sep, group, lowercase),
+        4 => scatter_groups::<4>(body, dst_body, sep, group, lowercase),
+        8 => scatter_groups::<8>(body, dst_body, sep, group, lowercase),
+        _ => scatter_groups::<0>(body, dst_body, sep, group, lowercase),
+    }
+}
+
+/// Small-group body: every group is followed by `sep`. `G` is the group
+/// size when known at compile time, or 0 to use the runtime `group`.
+#[inline]
+fn scatter_groups<const G: usize>(
+    src: &[u8],
+    dst: &mut [u8],
+    sep: &[u8],
+    group: usize,
+    lowercase: bool,
+) {
+    let group = if G == 0 { group } else { G };
+    let group_hex = encoded_len(group);
+    let stride = group_hex + sep.len();
+    let block_groups = BLOCK_IN / group;
+
+    let mut hex = [0u8; encoded_len(BLOCK_IN)];
+    for (block, out) in src
+        .chunks(block_groups * group)
+        .zip(dst.chunks_mut(block_groups * stride))
+    {
+        let block_hex = &mut hex[..encoded_len(block.len())];
+        // infallible because the buffer is pre-sized
+        let _ = encode_to_slice(block, block_hex, lowercase);
+        for (g_hex, slot) in block_hex
+            .chunks_exact(group_hex)
+            .zip(out.chunks_exact_mut(stride))
+        {
+            slot[..group_hex].copy_from_slice(g_hex);
+            if let [s] = sep {
+                slot[group_hex] = *s;
+            } else {
+                slot[group_hex..].copy_from_slice(sep);
+            }
+        }
+    }
+}
+
+/// Returns the number of bytes `src_hex` decodes to under `policy`.
+///
+/// This is a full check of the input, so it fails exactly when
+/// [`decode_to_slice_separated`] would (apart from the output size).
+///
+/// # Errors
+///
+/// See [`decode_to_slice_separated`].
+///
+/// # Examples
+/// ```
+/// use fast_hex_lite::{decoded_len_separated, SeparatorPolicy};
+///
+/// let n = decoded_len_separated(b"de:ad:be:ef", b":", SeparatorPolicy::BetweenBytes);
+/// assert_eq!(n, Ok(4));
+/// ```
+pub fn decoded_len_separated(
+    src_hex: &[u8],
+    seps: &[u8],
+    policy: SeparatorPolicy,
+) -> Result<usize, Error> {
+    scan(src_hex, &SepSet::new(seps), policy)
+}
+
+/// Decode hex that contains separators, such as `DE:AD:BE:EF`,
+/// `de-ad-be-ef` or `deadbeef 01020304`, straight from the original input.
+///
+/// `seps` is the set of allowed separator bytes (e.g. `b": -"`); hex digits
+/// in it are ignored. `policy` says where separators may appear. Like
+/// [`decode_to_slice`](crate::decode_to_slice), the whole input is checked
+/// before anything is written, so `dst` is unchanged on error.
+///
+/// Returns the number of bytes written.
+///
+/// # Errors
+///
+/// - [`Error::InvalidByte`] for the first byte that is neither hex nor an
+///   allowed separator, or is a separator (or digit) in a place `policy`
+///   does not allow. The index points into `src_hex`.
+/// - [`Error::OddLength`] if the number of hex digits is odd.
+/// - [`Error::OutputTooSmall`] if `dst` is too small.
+///
+/// # Panics
+///
+/// Panics if `policy` is `Grouped(0)`.
+///
+/// # Examples
+/// ```
+/// use fast_hex_lite::{decode_to_slice_separated, Error, SeparatorPolicy};
+///
+/// let mut out = [0u8; 4];
+/// let between = SeparatorPolicy::BetweenBytes;
+/// decode_to_slice_separated(b"DE:AD:BE:EF", &mut out, b":- ", between).unwrap();
+/// assert_eq!(out, [0xde, 0xad, 0xbe, 0xef]);
+///
+/// // Inside a byte is only allowed with `Anywhere`.
+/// let err = decode_to_slice_separated(b"d:ead", &mut out, b":", between).unwrap_err();
+/// assert_eq!(err, Error::InvalidByte { index: 1, byte: b':' });
+/// ```
+pub fn decode_to_slice_separated(
+    src_hex: &[u8],
+    dst: &mut [u8],
+    seps: &[u8],
+    policy: SeparatorPolicy,
+) -> Result<usize, Error> {
+    let set = SepSet::new(seps);
+
+    // --- PASS 1: layout + digits (no writes) ---
+    let out_len = scan(src_hex, &set, policy)?;
+    if dst.len() < out_len {
+        return Err(Error::OutputTooSmall);
+    }
+    let dst = &mut dst[..out_len];
+
+    // --- PASS 2: decode (writes) ---
+    if let SeparatorPolicy::Grouped(n) = policy {
+        let n = clamp_group(n, src_hex.len());
+        // Valid input is runs of exactly `2 * n` digits plus one separator.
+        for (run, out) in src_hex.chunks(2 * n + 1).zip(dst.chunks_mut(n)) {
+            decode_validated(&run[..encoded_len(out.len())], out);
+        }
+        return Ok(out_len);
+    }
+
+    // Otherwise gather digits into a stack block and decode it in one go.
+    let mut block = [0u8; GATHER_HEX];
+    let mut len = 0;
+    let mut out_off = 0;
+    for &b in src_hex {
+        if set.contains(b) {
+            continue;
+        }
+        block[len] = b;
+        len += 1;
+        if len == GATHER_HEX {
+            decode_validated(&block, &mut dst[out_off..out_off + GATHER_HEX / 2]);
+            out_off += GATHER_HEX / 2;
+            len = 0;
+        }
+    }
+    decode_validated(&block[..len], &mut dst[out_off..]);
+
+    Ok(out_len)
+}
+
+/// A group longer than the input covers all of it, so clamping `group` to
+/// `len` changes nothing and keeps `2 * group + 1` from overflowing.
+#[inline]
+fn clamp_group(group: usize, len: usize) -> usize {
+    group.min(len.max(1))
+}
+
+/// Allowed separator bytes. Hex digits are never separators.
+struct SepSet([bool; 256]);
+
+impl SepSet {
+    fn new(seps: &[u8]) -> Self {
+        let mut set = [false; 256];
+        for &b in seps {
+            set[usize::from(b)] = !b.is_ascii_hexdigit();
+        }
+        SepSet(set)
+    }
+
+    #[inline]
+    fn contains(&self, b: u8) -> bool {
+        self.0[usize::from(b)]
+    }
+}
+
+/// Pass 1: check digits and separator layout, return the decoded length.
+/// Errors are reported in input order.
+fn scan(src: &[u8], set: &SepSet, policy: SeparatorPolicy) -> Result<usize, Error> {
+    let invalid = |index: usize| Error::InvalidByte {
+        index,
+        byte: src[index],
+    };
+
+    let digits = match policy {
+        SeparatorPolicy::Grouped(n) => {
+            assert!(n > 0, "SeparatorPolicy::Grouped: group must be non-zero");
+            let n = clamp_group(n, src.len());
+            // Digit runs are checked in bulk (SIMD under `simd`); a separator
+            // inside a run is reported like any other non-hex byte.
+            let mut pos = 0;
+            while pos < src.len() {
+                let end = (pos + encoded_len(n)).min(src.len());
+                validate_digits(&src[pos..end], pos)?;
+                if end == src.len() {
+                    break;
+                }
+                if !set.contains(src[end]) {
+                    return Err(invalid(end));
+                }
+                if end + 1 == src.len() {
+                    // Trailing separator.
+                    return Err(invalid(end));
+                }
+                pos = end + 1;
+            }
+            src.len() - src.len() / (encoded_len(n) + 1)
+        }
+        SeparatorPolicy::BetweenBytes => {
+            let mut digits = 0;
+            let mut prev_sep = false;
+            for (i, &b) in src.iter().enumerate() {
+                if b.is_ascii_hexdigit() {
+                    digits += 1;
+                    prev_sep = false;
+                } else if set.contains(b) && digits % 2 == 0 && digits > 0 && !prev_sep {
+                    prev_sep = true;
+                } else {
+                    return Err(invalid(i));
+                }
+            }
+            if prev_sep {
+                return Err(invalid(src.len() - 1));
+            }
+            digits
+        }
+        SeparatorPolicy::Anywhere => {
+            let mut digits = 0;
+            for (i, &b) in src.iter().enumerate() {
+                if b.is_ascii_hexdigit() {
+                    digits += 1;
+                } else if !set.contains(b) {
+                    return Err(invalid(i));
+                }
+            }
+            digits
+        }
+    };
+
+    crate::decoded_len(digits)
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+    use super::*;
+    use std::prelude::v1::*;
+
+    /// Reference: hex per group, joined with `sep`.
+    fn reference(src: &[u8], sep: &[u8], group: usize, lowercase: bool) -> Vec<u8> {
+        let groups: Vec<String> = src
+            .chunks(group)
+            .map(|g| {
+                g.iter()
+                    .map(|b| {
+                        if lowercase {
+                            std::format!("{b:02x}")
+                        } else {
+                            std::format!("{b:02X}")
+                        }
+                    })
+                    .collect()
+            })
+            .collect();
+        groups.join(core::str::from_utf8(sep).unwrap()).into_bytes()
+    }
+
+    #[test]
+    fn test_encoded_len_separated() {
+        assert_eq!(encoded_len_separated(0, 3, 2), 0);
+        assert_eq!(encoded_len_separated(1, 1, 1), 2);
+        assert_eq!(encoded_len_separated(2, 1, 1), 5);
+        assert_eq!(encoded_len_separated(5, 2, 2), 14);
+        assert_eq!(encoded_len_separated(6, 0, 1), 12);
+    }
+
+    #[test]
+    fn test_separated_matches_reference() {
+        let src: Vec<u8> = (0..300u32)
+            .map(|i| u8::try_from(i * 29 % 256).unwrap())
+            .collect();
+        for sep in [&b""[..], b":", b" ", b"-", b", "] {
+            for group in [1, 2, 3, 4, 8, 15, 16, 17, 32, 200] {
+                for len in [0, 1, 2, 15, 16, 17, 33, 48, 49, 127, 128, 129, 300] {
+                    for lowercase in [true, false] {
+                        let expected = reference(&src[..len], sep, group, lowercase);
+                        let mut out = std::vec![0xAAu8; expected.len() + 2];
+                        let n =
+                            encode_to_slice_separated(&src[..len], &mut out, sep, group, lowercase);
+                        assert_eq!(n, Ok(expected.len()));
+                        assert_eq!(
+                            &out[..expected.len()],
+                            &expected[..],
+                            "sep={sep:?} group={group} len={len}"
+                        );
+                        assert_eq!(&out[expected.len()..], &[0xAA, 0xAA]);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_byte_separator_every_length() {
+        // Every tail length around the 16-byte SIMD chunks.
+        let src: Vec<u8> = (0u8..=255).collect();
+        for len in 0..=100 {
+            let expected = reference(&src[..len], b":", 1, true);
+            let mut out = std::vec![0u8; expected.len()];
+            encode_to_slice_separated(&src[..len], &mut out, b":", 1, true).unwrap();
+            assert_eq!(out, expected, "len={len}");
+        }
+    }
+
+    #[test]
+    fn test_separated_output_too_small() {
+        let mut out = [0u8; 10];
+        assert_eq!(
+            encode_to_slice_separated(&[1, 2, 3, 4], &mut out, b":", 1, true),
+            Err(Error::OutputTooSmall)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "group must be non-zero")]
+    fn test_separated_zero_group_panics() {
+        let _ = encode_to_slice_separated(&[1], &mut [0u8; 2], b":", 0, true);
+    }
+
+    // ── decode ─────────────────────────────────────────────────────────────
+
+    fn decode_sep(src: &[u8], seps: &[u8], policy: SeparatorPolicy) -> Result<Vec<u8>, Error> {
+        let mut out = std::vec![0u8; src.len() / 2];
+        let n = decode_to_slice_separated(src, &mut out, seps, policy)?;
+        assert_eq!(decoded_len_separated(src, seps, policy), Ok(n));
+        out.truncate(n);
+        Ok(out)
+    }
+
+    fn bad(index: usize, byte: u8) -> Error {
+        Error::InvalidByte { index, byte }
+    }
+
+    #[test]
+    fn test_decode_separated_common_forms() {
+        let want = std::vec![0xde, 0xad, 0xbe, 0xef];
+        for policy in [
+            SeparatorPolicy::BetweenBytes,
+            SeparatorPolicy::Grouped(1),
+            SeparatorPolicy::Anywhere,
+        ] {
+            for src in [
+                &b"DE:AD:BE:EF"[..],
+                b"de-ad-be-ef",
+                b"de ad be ef",
+                b"de:ad-be ef",
+            ] {
+                assert_eq!(decode_sep(src, b": -", policy), Ok(want.clone()));
+            }
+        }
+        assert_eq!(
+            decode_sep(b"dead beef", b" ", SeparatorPolicy::Grouped(2)),
+            Ok(want.clone())
+        );
+        assert_eq!(
+            decode_sep(b"", b":", SeparatorPolicy::Grouped(2)),
+            Ok(std::vec![])
+        );
+    }
+
+    #[test]
+    fn test_decode_separated_roundtrips_encoder_output() {
+        let src: Vec<u8> = (0..300u32)
+            .map(|i| u8::try_from(i * 11 % 256).unwrap())
+            .collect();
+        for group in [1, 2, 3, 4, 16, 20, 300] {
+            for len in [0, 1, 2, 19, 20, 21, 299, 300] {
+                let mut hex = std::vec![0u8; encoded_len_separated(len, 1, group)];
+                encode_to_slice_separated(&src[..len], &mut hex, b":", group, false).unwrap();
+                for policy in [SeparatorPolicy::Grouped(group), SeparatorPolicy::Anywhere] {
+                    assert_eq!(
+                        decode_sep(&hex, b":", policy),
+                        Ok(src[..len].to_vec()),
+                        "group={group} len={len} policy={policy:?}"
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_decode_separated_between_bytes_rules() {
+        let p = SeparatorPolicy::BetweenBytes;
+        // Optional at byte boundaries.
+        assert_eq!(
+            decode_sep(b"de:adbeef", b":", p),
+            Ok(std::vec![0xde, 0xad, 0xbe, 0xef])
+        );
+        assert_eq!(decode_sep(b"d:e", b":", p), Err(bad(1, b':')));
+        assert_eq!(decode_sep(b":de", b":", p), Err(bad(0, b':')));
+        assert_eq!(decode_sep(b"de:", b":", p), Err(bad(2, b':')));
+        assert_eq!(decode_sep(b"de::ad", b":", p), Err(bad(3, b':')));
+        assert_eq!(decode_sep(b"de-ad", b":", p), Err(bad(2, b'-')));
+        assert_eq!(decode_sep(b"de:a", b":", p), Err(Error::OddLength));
+    }
+
+    #[test]
+    fn test_decode_separated_grouped_rules() {
+        let p = SeparatorPolicy::Grouped(2);
+        assert_eq!(decode_sep(b"deadbe", b" ", p), Err(bad(4, b'b')));
+        assert_eq!(decode_sep(b"de ad", b" ", p), Err(bad(2, b' ')));
+        assert_eq!(decode_sep(b"dead ", b" ", p), Err(bad(4, b' ')));
+        assert_eq!(decode_sep(b"dead  beef", b" ", p), Err(bad(5, b' ')));
+        assert_eq!(
+            decode_sep(b"dead be", b" ", p),
+            Ok(std::vec![0xde, 0xad, 0xbe])
+        );
+        assert_eq!(decode_sep(b"dead b", b" ", p), Err(Error::OddLength));
+        assert_eq!(decode_sep(b"dead bxef", b" ", p), Err(bad(6, b'x')));
+
+        // A group larger than the input is the whole input; no overflow.
+        let huge = SeparatorPolicy::Grouped(usize::MAX);
+        assert_eq!(
+            decode_sep(b"deadbeef", b" ", huge).unwrap(),
+            [0xde, 0xad, 0xbe, 0xef]
+        );
+        assert_eq!(decode_sep(b"dead beef", b" ", huge), Err(bad(4, b' ')));
+        assert_eq!(decode_sep(b"", b" ", huge).unwrap(), []);
+        let mut out = [0u8; 8];
+        assert_eq!(
+            encode_to_slice_separated(&[0xde, 0xad], &mut out, b":", usize::MAX, true),
+            Ok(4)
+        );
+        assert_eq!(&out[..4], b"dead");
+    }
+
+    #[test]
+    fn test_decode_separated_anywhere_rules() {
+        let p = SeparatorPolicy::Anywhere;
+        assert_eq!(
+            decode_sep(b" d e:a d ", b": ", p),
+            Ok(std::vec![0xde, 0xad])
+        );
+        assert_eq!(decode_sep(b"de:ad", b" ", p), Err(bad(2, b':')));
+        assert_eq!(decode_sep(b"d:e:a", b":", p), Err(Error::OddLength));
+    }
+
+    #[test]
+    fn test_decode_separated_indices_point_into_original_input() {
+        // Long input so the SIMD validators and the gather blocks are used.
+        let src: Vec<u8> = (0..200u32)
+            .map(|i| u8::try_from(i * 3 % 256).unwrap())
+            .collect();
+        let mut hex = std::vec![0u8; encoded_len_separated(200, 1, 1)];
+        encode_to_slice_separated(&src, &mut hex, b":", 1, true).unwrap();
+        for pos in (0..hex.len()).filter(|p| p % 3 != 2).step_by(7) {
+            let mut corrupt = hex.clone();
+            corrupt[pos] = b'g';
+            for policy in [
+                SeparatorPolicy::BetweenBytes,
+                SeparatorPolicy::Grouped(1),
+                SeparatorPolicy::Anywhere,
+            ] {
+                let mut out = std::vec![0x55u8; 200];
+                assert_eq!(
+                    decode_to_slice_separated(&corrupt, &mut out, b":", policy),
+                    Err(bad(pos, b'g'))
+                );
+                assert!(out.iter().all(|&b| b == 0x55), "no partial writes");
+            }
+        }
+    }
+
+    #[test]
+    fn test_decode_separated_hex_digit_is_never_a_separator() {
+        let p = SeparatorPolicy::Anywhere;
+        assert_eq!(decode_sep(b"de:ad", b":a", p), Ok(std::vec![0xde, 0xad]));
+    }
+
+    #[test]
+    fn test_decode_separated_output_too_small() {
+        let mut out = [0u8; 1];
+        assert_eq!(
+            decode_to_slice_separated(b"de:ad", &mut out, b":", SeparatorPolicy::BetweenBytes),
+            Err(Error::OutputTooSmall)
+        );
+    }
+}
@@ -1,4 +1,4 @@
-//! SIMD-accelerated hex decoder (feature `simd`) — STABLE (`core::arch`)
+//! SIMD-accelerated hex decoder/encoder (feature `simd`) — STABLE (`core::arch`)
 //!
 //! Goals (in order):
 //! - **Correctness** (no partial writes on error)
@@ -6,68 +6,494 @@
 //! - **Universality** (`x86_64` + aarch64 fast paths, scalar fallback)
 //! - **Speed**
 //!
-//! Strategy:
+//! Strategy (decode):
 //! - Validate & map 16 ASCII hex chars -> 16 nibbles (0..15)
 //! - Store nibbles to a small stack array and pack pairs into bytes
+//! - Tails past the last full chunk reuse the 16-char kernel on an
+//!   overlapping final chunk; inputs under 16 chars run through it padded
+//! - On `x86_64`, wider kernels (AVX2, AVX-512 BW+VBMI) are picked once at
+//!   runtime via `is_x86_feature_detected!` (with `std`) or at compile time
+//!   from `cfg(target_feature)` (without); each tier hands its remainder to
+//!   the next narrower one, down to SSE2
+//!
+//! Strategy (encode):
+//! - Split 16 input bytes into high / low nibbles
+//! - Map nibbles to ASCII and interleave them into 32 hex chars
 //! - Scalar tail for the remainder
 
-use crate::{decode::decode_scalar, Error};
+use crate::backend::Backend;
+#[cfg(feature = "std")]
+use crate::backend::BACKEND_ENV;
+use crate::validate::{
+    letter_case_scalar, validate_case_scalar, validate_hex_scalar, CasePolicy, HAS_LOWER, HAS_UPPER,
+};
+use crate::{decode::decode_scalar, encode::encode_scalar, Error};
+use core::sync::atomic::{AtomicU8, Ordering};
+
+#[cfg(all(target_arch = "x86_64", target_feature = "sse2"))]
+mod avx2;
+#[cfg(all(target_arch = "x86_64", target_feature = "sse2"))]
+mod avx512;
+mod sep;
 
-#[cfg(target_arch = "x86_64")]
+pub(crate) use sep::encode_group_sep_simd;
+
+#[cfg(all(target_arch = "x86_64", target_feature = "sse2"))]
 use core::arch::x86_64::{
-    __m128i, _mm_add_epi8, _mm_and_si128, _mm_cmpgt_epi8, _mm_cmplt_epi8, _mm_loadu_si128,
-    _mm_movemask_epi8, _mm_or_si128, _mm_packus_epi16, _mm_set1_epi16, _mm_set1_epi8,
-    _mm_setzero_si128, _mm_slli_epi16, _mm_srli_epi16, _mm_storel_epi64,
+    __m128i, _mm_add_epi8, _mm_and_si128, _mm_andnot_si128, _mm_cmpeq_epi8, _mm_cmpgt_epi8,
+    _mm_cmplt_epi8, _mm_loadu_si128, _mm_movemask_epi8, _mm_or_si128, _mm_packus_epi16,
+    _mm_set1_epi16, _mm_set1_epi8, _mm_setzero_si128, _mm_sfence, _mm_slli_epi16, _mm_srli_epi16,
+    _mm_storel_epi64, _mm_storeu_si128, _mm_stream_si128, _mm_unpackhi_epi8, _mm_unpacklo_epi64,
+    _mm_unpacklo_epi8,
 };
 
-#[cfg(target_arch = "aarch64")]
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
 use core::arch::aarch64::{
-    uint16x8_t, uint8x16_t, uint8x8_t, vaddq_u8, vandq_u16, vandq_u8, vbslq_u8, vcgeq_u8, vcleq_u8,
-    vdupq_n_u16, vdupq_n_u8, vld1q_u8, vminvq_u8, vmovn_u16, vorrq_u16, vorrq_u8,
-    vreinterpretq_u16_u8, vshlq_n_u16, vshrq_n_u16, vst1_u8, vst1q_u8, vsubq_u8,
+    uint16x8_t, uint8x16_t, uint8x8_t, vaddq_u8, vandq_u16, vandq_u8, vbicq_u8, vbslq_u8, vcgeq_u8,
+    vcleq_u8, vdupq_n_u16, vdupq_n_u8, vld1q_u8, vmaxvq_u8, vminvq_u8, vmovn_u16, vorrq_u16,
+    vorrq_u8, vqtbl1q_u8, vreinterpretq_u16_u8, vshlq_n_u16, vshrq_n_u16, vshrq_n_u8, vst1_u8,
+    vst1q_u8, vsubq_u8, vtstq_u8, vzip1q_u8, vzip2q_u8,
 };
 
 const CHUNK_HEX: usize = 16;
 const CHUNK_OUT: usize = 8;
 
+// Targets without a baseline kernel run the scalar tail over the whole input.
+// Kernels are gated on the baseline target feature, not just the arch, so
+// soft-float targets such as `x86_64-unknown-none` build with scalar code.
+const HAS_BASE_KERNEL: bool = cfg!(any(
+    all(target_arch = "x86_64", target_feature = "sse2"),
+    all(target_arch = "aarch64", target_feature = "neon")
+));
+
+// Stack block for in-place decoding; a multiple of every kernel width.
+const IN_PLACE_HEX: usize = 256;
+
+// Block for fused decode: input plus output stay well inside L1.
+const FUSED_BLOCK_HEX: usize = 4096;
+
+const ENC_CHUNK_IN: usize = 16;
+const ENC_CHUNK_HEX: usize = 32;
+
+// ── Runtime dispatch ───────────────────────────────────────────────────────
+
+/// Decode kernel in use. Discriminants are ordered by width, so a level is
+/// usable iff it is `<=` the level detected for the CPU.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub(crate) enum Level {
+    /// Scalar only, pinned via `set_backend` or the environment.
+    Scalar = 1,
+    /// SSE2 / NEON (baseline on `x86_64` / aarch64), scalar elsewhere.
+    Base = 2,
+    /// AVX2: 32 hex chars per iteration.
+    #[cfg(all(target_arch = "x86_64", target_feature = "sse2"))]
+    Avx2 = 3,
+    /// AVX-512 BW + VBMI: 64 hex chars per iteration.
+    #[cfg(all(target_arch = "x86_64", target_feature = "sse2"))]
+    Avx512 = 4,
+}
+
+// 0 = not detected yet; otherwise a `Level` discriminant.
+static LEVEL: AtomicU8 = AtomicU8::new(0);
+
+/// Returns the cached dispatch level, detecting CPU features on first use.
+#[inline]
+pub(crate) fn level() -> Level {
+    level_in(&LEVEL)
+}
+
+/// [`level`] on a given cell, so tests can use a fresh one.
+#[inline]
+fn level_in(cell: &AtomicU8) -> Level {
+    match cell.load(Ordering::Relaxed) {
+        0 => {
+            // Only fill in an empty cell: a `set_backend` that lands while
+            // we detect must not be overwritten. Racing first calls detect
+            // the same value, so whichever stored first is the answer.
+            let detected = detect_level();
+            match cell.compare_exchange(0, detected as u8, Ordering::Relaxed, Ordering::Relaxed) {
+                Ok(_) => detected,
+                Err(current) => level_from_raw(current),
+            }
+        }
+        raw => level_from_raw(raw),
+    }
+}
+
+/// Decode a non-zero `LEVEL` value.
+#[inline]
+fn level_from_raw(raw: u8) -> Level {
+    match raw {
+        1 => Level::Scalar,
+        #[cfg(all(target_arch = "x86_64", target_feature = "sse2"))]
+        3 => Level::Avx2,
+        #[cfg(all(target_arch = "x86_64", target_feature = "sse2"))]
+        4 => Level::Avx512,
+        _ => Level::Base,
+    }
+}
+
+/// `true` if the 16-char baseline kernel may run (exists for this target
+/// and the scalar backend is not pinned).
+#[inline]
+fn use_base_kernel() -> bool {
+    HAS_BASE_KERNEL && level() != Level::Scalar
+}
+
+/// Current backend, as reported by [`crate::backend`].
+pub(crate) fn backend() -> Backend {
+    match level() {
+        Level::Scalar => Backend::Scalar,
+        #[cfg(all(target_arch = "x86_64", target_feature = "sse2"))]
+        Level::Base => Backend::Sse2,
+        #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+        Level::Base => Backend::Neon,
+        #[cfg(not(any(
+            all(target_arch = "x86_64", target_feature = "sse2"),
+            all(target_arch = "aarch64", target_feature = "neon")
+        )))]
+        Level::Base => Backend::Scalar,
+        #[cfg(all(target_arch = "x86_64", target_feature = "sse2"))]
+        Level::Avx2 => Backend::Avx2,
+        #[cfg(all(target_arch = "x86_64", target_feature = "sse2"))]
+        Level::Avx512 => Backend::Avx512,
+    }
+}
+
+/// Pin `backend` if this CPU supports it; see [`crate::set_backend`].
+pub(crate) fn set_backend(backend: Backend) -> bool {
+    set_backend_in(&LEVEL, backend)
+}
+
+/// [`set_backend`] on a given cell. A plain store: an explicit pin replaces
+/// whatever is there, detected or pinned, and detection never replaces a
+/// pin (see [`level_in`]).
+fn set_backend_in(cell: &AtomicU8, backend: Backend) -> bool {
+    match level_for(backend) {
+        Some(level) if level as u8 <= hardware_level() as u8 => {
+            cell.store(level as u8, Ordering::Relaxed);
+            true
+        }
+        _ => false,
+    }
+}
+
+fn level_for(backend: Backend) -> Option<Level> {
+    match backend {
+        Backend::Scalar => Some(Level::Scalar),
+        #[cfg(all(target_arch = "x86_64", target_feature = "sse2"))]
+        Backend::Sse2 => Some(Level::Base),
+        #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+        Backend::Neon => Some(Level::Base),
+        #[cfg(all(target_arch = "x86_64", target_feature = "sse2"))]
+        Backend::Avx2 => Some(Level::Avx2),
+        #[cfg(all(target_arch = "x86_64", target_feature = "sse2"))]
+        Backend::Avx512 => Some(Level::Avx512),
+        _ => None,
+    }
+}
+
+/// Hardware level, then an optional downgrade from `FAST_HEX_LITE_BACKEND`
+/// (read here only, i.e. once, and never if `set_backend` ran first).
+/// Unknown or unsupported values are ignored.
+fn detect_level() -> Level {
+    let hw = hardware_level();
+    #[cfg(feature = "std")]
+    {
+        let pinned = std::env::var(BACKEND_ENV)
+            .ok()
+            .and_then(|name| Backend::from_name(&name))
+            .and_then(level_for);
+        if let Some(level) = pinned {
+            if level as u8 <= hw as u8 {
+                return level;
+            }
+        }
+    }
+    hw
+}
+
+/// Widest tier the CPU supports: detected at runtime with `std`, otherwise
+/// fixed at compile time by the enabled target features (e.g.
+/// `-C target-cpu=native` or `-C target-feature=+avx2`).
+fn hardware_level() -> Level {
+    // The AVX-512 tier hands its remainder to the AVX2 one, so require both.
+    #[cfg(all(target_arch = "x86_64", target_feature = "sse2", feature = "std"))]
+    {
+        if std::is_x86_feature_detected!("avx512f")
+            && std::is_x86_feature_detected!("avx512bw")
+            && std::is_x86_feature_detected!("avx512vbmi")
+            && std::is_x86_feature_detected!("avx2")
+        {
+            return Level::Avx512;
+        }
+        if std::is_x86_feature_detected!("avx2") {
+            return Level::Avx2;
+        }
+    }
+    #[cfg(all(target_arch = "x86_64", target_feature = "sse2", not(feature = "std")))]
+    {
+        if cfg!(all(
+            target_feature = "avx512f",
+            target_feature = "avx512bw",
+            target_feature = "avx512vbmi",
+            target_feature = "avx2"
+        )) {
+            return Level::Avx512;
+        }
+        if cfg!(target_feature = "avx2") {
+            return Level::Avx2;
+        }
+    }
+    Level::Base
+}
+
 pub(crate) fn decode_to_slice_simd(src_hex: &[u8], dst: &mut [u8]) -> Result<usize, Error> {
     let out_len = dst.len();
     debug_assert_eq!(src_hex.len(), out_len * 2);
 
-    let iters = out_len / CHUNK_OUT;
-    let tail_hex_start = iters * CHUNK_HEX;
+    // --- PASS 1: validate (no writes) ---
+    validate_simd(src_hex, 0)?;
+
+    // --- PASS 2: decode (writes) ---
+    #[cfg(all(target_arch = "x86_64", target_feature = "sse2"))]
+    if use_streaming(out_len) {
+        decode_validated_stream(src_hex, dst);
+        return Ok(out_len);
+    }
+    decode_validated_simd(src_hex, dst);
+
+    Ok(out_len)
+}
+
+/// Validate `src_hex` with the widest kernel available on this CPU.
+/// Reported indices are offset by `hex_base`.
+///
+/// No scalar loop for 16 chars or more: the tiers take the largest multiple
+/// of 16, and the rest is covered by one 16-char chunk overlapping bytes
+/// that are already known valid, so its first bad lane is still the first
+/// bad byte. Shorter inputs go through the padded small-input kernel.
+#[inline]
+pub(crate) fn validate_simd(src_hex: &[u8], hex_base: usize) -> Result<(), Error> {
+    validate_case_simd(src_hex, hex_base, CasePolicy::Any)
+}
+
+/// [`validate_simd`] accepting only the letters of `case`. A letter of the
+/// other case is reported as `InvalidByte` like any other rejected byte;
+/// the caller tells the two apart.
+pub(crate) fn validate_case_simd(
+    src_hex: &[u8],
+    hex_base: usize,
+    case: CasePolicy,
+) -> Result<(), Error> {
+    let len = src_hex.len();
+    if !use_base_kernel() || len < CHUNK_HEX {
+        return validate_small(src_hex, hex_base, case);
+    }
+
+    let body = len - len % CHUNK_HEX;
+    validate_tiered(&src_hex[..body], hex_base, case)?;
+    if body != len {
+        validate_chunk16(
+            &src_hex[len - CHUNK_HEX..],
+            hex_base + len - CHUNK_HEX,
+            case,
+        )?;
+    }
+    Ok(())
+}
+
+/// Decode already-validated `src_hex` into `dst` with the widest kernel
+/// available on this CPU. Tails are handled as in [`validate_simd`]; the
+/// overlapping chunk rewrites a few output bytes with the same values.
+pub(crate) fn decode_validated_simd(src_hex: &[u8], dst: &mut [u8]) {
+    debug_assert_eq!(src_hex.len(), dst.len() * 2);
+
+    let len = src_hex.len();
+    if !use_base_kernel() || len < CHUNK_HEX {
+        decode_small(src_hex, dst);
+        return;
+    }
+
+    let body = len - len % CHUNK_HEX;
+    decode_tiered(&src_hex[..body], &mut dst[..body / 2]);
+    if body != len {
+        let off = len - CHUNK_HEX;
+        decode_chunk16(&src_hex[off..], &mut dst[off / 2..]);
+    }
+}
+
+fn validate_tiered(src_hex: &[u8], hex_base: usize, case: CasePolicy) -> Result<(), Error> {
+    // SAFETY: each tier is only selected after `level()` detected its CPU features.
+    match level() {
+        #[cfg(all(target_arch = "x86_64", target_feature = "sse2"))]
+        Level::Avx512 => unsafe { avx512::validate_avx512(src_hex, hex_base, case) },
+        #[cfg(all(target_arch = "x86_64", target_feature = "sse2"))]
+        Level::Avx2 => unsafe { avx2::validate_avx2(src_hex, hex_base, case) },
+        Level::Base => validate_base(src_hex, hex_base, case),
+        Level::Scalar => validate_case_scalar(src_hex, hex_base, case),
+    }
+}
+
+fn decode_tiered(src_hex: &[u8], dst: &mut [u8]) {
+    // SAFETY: each tier is only selected after `level()` detected its CPU features.
+    match level() {
+        #[cfg(all(target_arch = "x86_64", target_feature = "sse2"))]
+        Level::Avx512 => unsafe { avx512::decode_avx512(src_hex, dst) },
+        #[cfg(all(target_arch = "x86_64", target_feature = "sse2"))]
+        Level::Avx2 => unsafe { avx2::decode_avx2(src_hex, dst) },
+        Level::Base => decode_base(src_hex, dst),
+        Level::Scalar => {
+            // Validated by the caller, or trusted input that may decode to garbage.
+            let _ = decode_scalar(src_hex, dst);
+        }
+    }
+}
+
+// ── Small-input kernel (< 16 chars) ────────────────────────────────────────
+
+/// Validate fewer than 16 chars: copy them into a 16-byte block padded with
+/// `'0'` and run one baseline chunk. Padding lanes are valid, so any error
+/// lane is a real input byte.
+fn validate_small(src_hex: &[u8], hex_base: usize, case: CasePolicy) -> Result<(), Error> {
+    if !use_base_kernel() {
+        return validate_case_scalar(src_hex, hex_base, case);
+    }
+    debug_assert!(src_hex.len() < CHUNK_HEX);
+    if src_hex.is_empty() {
+        return Ok(());
+    }
+    let mut block = [b'0'; CHUNK_HEX];
+    block[..src_hex.len()].copy_from_slice(src_hex);
+    validate_chunk16(&block, hex_base, case)
+}
+
+/// Decode fewer than 16 already-validated chars through a padded block.
+fn decode_small(src_hex: &[u8], dst: &mut [u8]) {
+    if !use_base_kernel() {
+        // Validated by the caller, or trusted input that may decode to garbage.
+        let _ = decode_scalar(src_hex, dst);
+        return;
+    }
+    debug_assert!(src_hex.len() < CHUNK_HEX);
+    if src_hex.is_empty() {
+        return;
+    }
+    let mut block = [b'0'; CHUNK_HEX];
+    block[..src_hex.len()].copy_from_slice(src_hex);
+    let mut out = [0u8; CHUNK_OUT];
+    decode_chunk16(&block, &mut out);
+    dst.copy_from_slice(&out[..dst.len()]);
+}
+
+/// Fused decode: validate and decode each `FUSED_BLOCK_HEX` block while it is
+/// still in L1. On error, earlier blocks have already been written.
+pub(crate) fn decode_fused_simd(src_hex: &[u8], dst: &mut [u8]) -> Result<usize, Error> {
+    debug_assert_eq!(src_hex.len(), dst.len() * 2);
+
+    let blocks = src_hex
+        .chunks(FUSED_BLOCK_HEX)
+        .zip(dst.chunks_mut(FUSED_BLOCK_HEX / 2));
+    for (i, (src, out)) in blocks.enumerate() {
+        validate_simd(src, i * FUSED_BLOCK_HEX)?;
+        decode_validated_simd(src, out);
+    }
+
+    Ok(dst.len())
+}
+
+/// In-place decode: `buf` holds ASCII hex, the result lands in its front half.
+///
+/// Input is validated up front, so on error `buf` is unchanged. Decoding then
+/// walks forward in blocks copied to the stack: the output of block `i` ends
+/// at `(i + 1) * IN_PLACE_HEX / 2`, which never passes the start of the next
+/// unread block, so no input is overwritten before it is read.
+pub(crate) fn decode_in_place_simd(buf: &mut [u8]) -> Result<usize, Error> {
+    let out_len = buf.len() / 2;
 
     // --- PASS 1: validate (no writes) ---
-    #[cfg(target_arch = "x86_64")]
+    validate_simd(buf, 0)?;
+
+    // --- PASS 2: decode forward (writes) ---
+    decode_validated_in_place_simd(buf);
+
+    Ok(out_len)
+}
+
+/// Pass 2 of [`decode_in_place_simd`] for already-validated `buf`.
+pub(crate) fn decode_validated_in_place_simd(buf: &mut [u8]) {
+    let mut block = [0u8; IN_PLACE_HEX];
+    let mut hex_off = 0;
+    while hex_off < buf.len() {
+        let n = (buf.len() - hex_off).min(IN_PLACE_HEX);
+        block[..n].copy_from_slice(&buf[hex_off..hex_off + n]);
+        let out_off = hex_off / 2;
+        decode_validated_simd(&block[..n], &mut buf[out_off..out_off + n / 2]);
+        hex_off += n;
+    }
+}
+
+/// Validate `src_hex` with the baseline 16-char kernel (SSE2 / NEON) and a
+/// scalar tail. Only letters of `case` are accepted. Reported indices are
+/// offset by `hex_base`.
+pub(crate) fn validate_base(
+    src_hex: &[u8],
+    hex_base: usize,
+    case: CasePolicy,
+) -> Result<(), Error> {
+    let iters = if HAS_BASE_KERNEL {
+        src_hex.len() / CHUNK_HEX
+    } else {
+        0
+    };
+    let tail_hex_start = iters * CHUNK_HEX;
+
+    #[cfg(all(target_arch = "x86_64", target_feature = "sse2"))]
     unsafe {
         for i in 0..iters {
             let hex_off = i * CHUNK_HEX;
-            validate_chunk16_sse2(&src_hex[hex_off..hex_off + CHUNK_HEX], hex_off)?;
+            validate_chunk16_sse2(
+                &src_hex[hex_off..hex_off + CHUNK_HEX],
+                hex_base + hex_off,
+                case,
+            )?;
         }
     }
 
-    #[cfg(target_arch = "aarch64")]
+    #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
     unsafe {
         for i in 0..iters {
             let hex_off = i * CHUNK_HEX;
-            validate_chunk16_neon(&src_hex[hex_off..hex_off + CHUNK_HEX], hex_off)?;
+            validate_chunk16_neon(
+                &src_hex[hex_off..hex_off + CHUNK_HEX],
+                hex_base + hex_off,
+                case,
+            )?;
         }
     }
 
-    #[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64")))]
-    {
-        // No SIMD backend for this arch → full scalar fallback.
-        return decode_scalar(src_hex, dst);
-    }
-
     // Validate tail (scalar, no writes).
     let tail_hex = &src_hex[tail_hex_start..];
     if !tail_hex.is_empty() {
-        validate_hex_scalar(tail_hex, tail_hex_start)?;
+        validate_case_scalar(tail_hex, hex_base + tail_hex_start, case)?;
     }
 
-    // --- PASS 2: decode (writes) ---
-    #[cfg(target_arch = "x86_64")]
+    Ok(())
+}
+
+/// Decode already-validated `src_hex` into `dst` with the baseline 16-char
+/// kernel (SSE2 / NEON) and a scalar tail.
+pub(crate) fn decode_base(src_hex: &[u8], dst: &mut [u8]) {
+    debug_assert_eq!(src_hex.len(), dst.len() * 2);
+
+    let iters = if HAS_BASE_KERNEL {
+        dst.len() / CHUNK_OUT
+    } else {
+        0
+    };
+
+    #[cfg(all(target_arch = "x86_64", target_feature = "sse2"))]
     {
         for i in 0..iters {
             let hex_off = i * CHUNK_HEX;
@@ -85,7 +511,7 @@ pub(crate) fn decode_to_slice_simd(src_hex: &[u8], dst: &mut [u8]) -> Result<usi
         }
     }
 
-    #[cfg(target_arch = "aarch64")]
+    #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
     {
         for i in 0..iters {
             let hex_off = i * CHUNK_HEX;
@@ -103,34 +529,324 @@ pub(crate) fn decode_to_slice_simd(src_hex: &[u8], dst: &mut [u8]) -> Result<usi
         }
     }
 
+    let tail_hex = &src_hex[iters * CHUNK_HEX..];
     if !tail_hex.is_empty() {
         let tail_dst = &mut dst[iters * CHUNK_OUT..];
-        decode_scalar(tail_hex, tail_dst)?;
+        // The caller validated the input, so the scalar decoder cannot fail here.
+        let res = decode_scalar(tail_hex, tail_dst);
+        debug_assert!(res.is_ok());
     }
+}
 
-    Ok(out_len)
+pub(crate) fn encode_to_slice_simd(src: &[u8], dst_hex: &mut [u8], lowercase: bool) {
+    debug_assert_eq!(dst_hex.len(), src.len() * 2);
+
+    #[cfg(all(target_arch = "x86_64", target_feature = "sse2"))]
+    if use_streaming(dst_hex.len()) {
+        encode_stream(src, dst_hex, lowercase);
+        return;
+    }
+    encode_chunks(src, dst_hex, lowercase);
+}
+
+/// 16-byte chunks with regular stores, then a scalar tail.
+fn encode_chunks(src: &[u8], dst_hex: &mut [u8], lowercase: bool) {
+    let iters = if use_base_kernel() {
+        src.len() / ENC_CHUNK_IN
+    } else {
+        0
+    };
+
+    #[cfg(all(target_arch = "x86_64", target_feature = "sse2"))]
+    {
+        for i in 0..iters {
+            let in_off = i * ENC_CHUNK_IN;
+            let hex_off = i * ENC_CHUNK_HEX;
+
+            // SAFETY:
+            // - Slices are exactly 16 / 32 bytes long.
+            // - SSE2 is baseline on x86_64.
+            unsafe {
+                encode_chunk16_sse2(
+                    &src[in_off..in_off + ENC_CHUNK_IN],
+                    &mut dst_hex[hex_off..hex_off + ENC_CHUNK_HEX],
+                    lowercase,
+                );
+            }
+        }
+    }
+
+    #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+    {
+        for i in 0..iters {
+            let in_off = i * ENC_CHUNK_IN;
+            let hex_off = i * ENC_CHUNK_HEX;
+
+            // SAFETY:
+            // - Slices are exactly 16 / 32 bytes long.
+            // - NEON is baseline on aarch64.
+            unsafe {
+                encode_chunk16_neon(
+                    &src[in_off..in_off + ENC_CHUNK_IN],
+                    &mut dst_hex[hex_off..hex_off + ENC_CHUNK_HEX],
+                    lowercase,
+                );
+            }
+        }
+    }
+
+    // Scalar tail (the whole input on targets without a SIMD backend).
+    let tail_in = iters * ENC_CHUNK_IN;
+    encode_scalar(&src[tail_in..], &mut dst_hex[tail_in * 2..], lowercase);
 }
 
+// ── Streaming stores (x86_64) ─────────────────────────────────────────────
+
+/// Whether an output of `out_len` bytes is at or above the threshold set by
+/// [`crate::set_stream_threshold`].
+#[cfg(all(target_arch = "x86_64", target_feature = "sse2"))]
 #[inline]
-fn is_hex_ascii(b: u8) -> bool {
-    b.is_ascii_hexdigit()
+fn use_streaming(out_len: usize) -> bool {
+    use_base_kernel() && crate::backend::stream_threshold().is_some_and(|t| out_len >= t)
 }
 
-fn validate_hex_scalar(src_hex: &[u8], hex_base: usize) -> Result<(), Error> {
-    for (i, &b) in src_hex.iter().enumerate() {
-        if !is_hex_ascii(b) {
-            return Err(Error::InvalidByte {
-                index: hex_base + i,
-                byte: b,
-            });
-        }
+/// Decode already-validated `src_hex` like [`decode_validated_simd`], but
+/// write every 16-byte-aligned block of `dst` with non-temporal stores so a
+/// huge output does not evict the caller's working set. The unaligned head
+/// and the tail use regular stores.
+#[cfg(all(target_arch = "x86_64", target_feature = "sse2"))]
+pub(crate) fn decode_validated_stream(src_hex: &[u8], dst: &mut [u8]) {
+    debug_assert_eq!(src_hex.len(), dst.len() * 2);
+
+    let head = dst.as_ptr().align_offset(16).min(dst.len());
+    let body = (dst.len() - head) / 16 * 16;
+    let (dst_head, rest) = dst.split_at_mut(head);
+    let (dst_body, dst_tail) = rest.split_at_mut(body);
+    let (src_head, rest) = src_hex.split_at(head * 2);
+    let (src_body, src_tail) = rest.split_at(body * 2);
+
+    decode_validated_simd(src_head, dst_head);
+    // SAFETY:
+    // - `dst_body` starts 16-byte aligned and is a multiple of 16 bytes.
+    // - SSE2 is baseline on x86_64.
+    unsafe { decode_stream_sse2(src_body, dst_body) };
+    decode_validated_simd(src_tail, dst_tail);
+}
+
+/// Encode like [`encode_to_slice_simd`], but with non-temporal stores for
+/// every 32-byte block of `dst_hex` that starts 16-byte aligned.
+#[cfg(all(target_arch = "x86_64", target_feature = "sse2"))]
+pub(crate) fn encode_stream(src: &[u8], dst_hex: &mut [u8], lowercase: bool) {
+    debug_assert_eq!(dst_hex.len(), src.len() * 2);
+
+    // The head must end on an input byte boundary; an odd-aligned `dst_hex`
+    // can never reach 16-byte alignment that way, so it keeps regular stores.
+    let head_hex = dst_hex.as_ptr().align_offset(16);
+    if !head_hex.is_multiple_of(2) || head_hex > dst_hex.len() {
+        encode_chunks(src, dst_hex, lowercase);
+        return;
+    }
+    let head = head_hex / 2;
+    let body = (src.len() - head) / ENC_CHUNK_IN * ENC_CHUNK_IN;
+    let (src_head, rest) = src.split_at(head);
+    let (src_body, src_tail) = rest.split_at(body);
+    let (dst_head, rest) = dst_hex.split_at_mut(head * 2);
+    let (dst_body, dst_tail) = rest.split_at_mut(body * 2);
+
+    encode_chunks(src_head, dst_head, lowercase);
+    // SAFETY:
+    // - `dst_body` starts 16-byte aligned and is a multiple of 32 bytes.
+    // - SSE2 is baseline on x86_64.
+    unsafe { encode_stream_sse2(src_body, dst_body, lowercase) };
+    encode_chunks(src_tail, dst_tail, lowercase);
+}
+
+// ── Fixed-size kernels ─────────────────────────────────────────────────────
+
+/// Validate and decode exactly `dst.len() * 2` hex chars (at least 16) with
+/// the baseline 16-char kernel and no scalar tail: when the length is not a
+/// multiple of 16, the last chunk overlaps the previous one. With a constant
+/// length (`decode_to_array`) the loops unroll completely. The AVX2 /
+/// AVX-512 tiers are deliberately not used here, whatever `level()` says.
+///
+/// Writes to `dst` only after the whole input has been validated.
+#[inline]
+pub(crate) fn decode_fixed_base(src_hex: &[u8], dst: &mut [u8]) -> Result<(), Error> {
+    debug_assert_eq!(src_hex.len(), dst.len() * 2);
+    debug_assert!(src_hex.len() >= CHUNK_HEX);
+
+    if !use_base_kernel() {
+        validate_hex_scalar(src_hex, 0)?;
+        let res = decode_scalar(src_hex, dst);
+        debug_assert!(res.is_ok());
+        return Ok(());
+    }
+
+    let full = src_hex.len() / CHUNK_HEX;
+    let last = src_hex.len() - CHUNK_HEX;
+
+    // Chunks are checked in input order, and the overlapping last chunk only
+    // adds bytes past the previous one, so the first error is the first byte.
+    for i in 0..full {
+        let off = i * CHUNK_HEX;
+        validate_chunk16(&src_hex[off..off + CHUNK_HEX], off, CasePolicy::Any)?;
+    }
+    if !last.is_multiple_of(CHUNK_HEX) {
+        validate_chunk16(&src_hex[last..], last, CasePolicy::Any)?;
+    }
+
+    for i in 0..full {
+        let off = i * CHUNK_HEX;
+        decode_chunk16(
+            &src_hex[off..off + CHUNK_HEX],
+            &mut dst[off / 2..off / 2 + CHUNK_OUT],
+        );
     }
+    if !last.is_multiple_of(CHUNK_HEX) {
+        decode_chunk16(&src_hex[last..], &mut dst[last / 2..]);
+    }
+
     Ok(())
 }
 
-#[cfg(target_arch = "x86_64")]
+/// Encode exactly `src.len()` bytes (at least 16) with the baseline 16-byte
+/// kernel, overlapping the last chunk instead of running a scalar tail.
+#[inline]
+pub(crate) fn encode_fixed_base(src: &[u8], dst_hex: &mut [u8], lowercase: bool) {
+    debug_assert_eq!(dst_hex.len(), src.len() * 2);
+    debug_assert!(src.len() >= ENC_CHUNK_IN);
+
+    if !use_base_kernel() {
+        encode_scalar(src, dst_hex, lowercase);
+        return;
+    }
+
+    let full = src.len() / ENC_CHUNK_IN;
+    for i in 0..full {
+        let off = i * ENC_CHUNK_IN;
+        encode_chunk16(
+            &src[off..off + ENC_CHUNK_IN],
+            &mut dst_hex[off * 2..off * 2 + ENC_CHUNK_HEX],
+            lowercase,
+        );
+    }
+    let last = src.len() - ENC_CHUNK_IN;
+    if !last.is_multiple_of(ENC_CHUNK_IN) {
+        encode_chunk16(&src[last..], &mut dst_hex[last * 2..], lowercase);
+    }
+}
+
+// Per-architecture baseline chunk kernels; scalar where there is none.
+
+#[inline]
+fn validate_chunk16(src16: &[u8], hex_base: usize, case: CasePolicy) -> Result<(), Error> {
+    // SAFETY: SSE2 / NEON are baseline on x86_64 / aarch64; the kernels take
+    // unaligned 16-byte loads from a 16-byte slice.
+    #[cfg(all(target_arch = "x86_64", target_feature = "sse2"))]
+    unsafe {
+        validate_chunk16_sse2(src16, hex_base, case)
+    }
+    #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+    unsafe {
+        validate_chunk16_neon(src16, hex_base, case)
+    }
+    #[cfg(not(any(
+        all(target_arch = "x86_64", target_feature = "sse2"),
+        all(target_arch = "aarch64", target_feature = "neon")
+    )))]
+    {
+        validate_case_scalar(src16, hex_base, case)
+    }
+}
+
+#[inline]
+fn decode_chunk16(src16: &[u8], dst8: &mut [u8]) {
+    // SAFETY: as for `validate_chunk16`; `dst8` is exactly 8 bytes.
+    #[cfg(all(target_arch = "x86_64", target_feature = "sse2"))]
+    unsafe {
+        decode_chunk16_sse2(src16, dst8);
+    }
+    #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+    unsafe {
+        decode_chunk16_neon(src16, dst8);
+    }
+    #[cfg(not(any(
+        all(target_arch = "x86_64", target_feature = "sse2"),
+        all(target_arch = "aarch64", target_feature = "neon")
+    )))]
+    {
+        let res = decode_scalar(src16, dst8);
+        debug_assert!(res.is_ok());
+    }
+}
+
+#[inline]
+fn encode_chunk16(src16: &[u8], dst32: &mut [u8], lowercase: bool) {
+    // SAFETY: as for `validate_chunk16`; `dst32` is exactly 32 bytes.
+    #[cfg(all(target_arch = "x86_64", target_feature = "sse2"))]
+    unsafe {
+        encode_chunk16_sse2(src16, dst32, lowercase);
+    }
+    #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+    unsafe {
+        encode_chunk16_neon(src16, dst32, lowercase);
+    }
+    #[cfg(not(any(
+        all(target_arch = "x86_64", target_feature = "sse2"),
+        all(target_arch = "aarch64", target_feature = "neon")
+    )))]
+    {
+        encode_scalar(src16, dst32, lowercase);
+    }
+}
+
+/// Scan `src` for hex letters, returning `HAS_LOWER` / `HAS_UPPER` flags.
+/// Stops early once both cases have been seen.
+pub(crate) fn letter_case_simd(src: &[u8]) -> u8 {
+    let iters = if use_base_kernel() {
+        src.len() / CHUNK_HEX
+    } else {
+        0
+    };
+
+    let mut flags = 0;
+    for i in 0..iters {
+        let off = i * CHUNK_HEX;
+
+        // SAFETY:
+        // - Slice is exactly 16 bytes long.
+        // - SSE2 / NEON are baseline on x86_64 / aarch64.
+        #[cfg(all(target_arch = "x86_64", target_feature = "sse2"))]
+        unsafe {
+            flags |= letter_case_chunk16_sse2(&src[off..off + CHUNK_HEX]);
+        }
+        #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+        unsafe {
+            flags |= letter_case_chunk16_neon(&src[off..off + CHUNK_HEX]);
+        }
+        #[cfg(not(any(
+            all(target_arch = "x86_64", target_feature = "sse2"),
+            all(target_arch = "aarch64", target_feature = "neon")
+        )))]
+        {
+            flags |= letter_case_scalar(&src[off..off + CHUNK_HEX]);
+        }
+
+        if flags == HAS_LOWER | HAS_UPPER {
+            return flags;
+        }
+    }
+
+    flags | letter_case_scalar(&src[iters * CHUNK_HEX..])
+}
+
+#[cfg(all(target_arch = "x86_64", target_feature = "sse2"))]
 #[target_feature(enable = "sse2")]
-unsafe fn validate_chunk16_sse2(src16: &[u8], hex_base: usize) -> Result<(), Error> {
+unsafe fn validate_chunk16_sse2(
+    src16: &[u8],
+    hex_base: usize,
+    case: CasePolicy,
+) -> Result<(), Error> {
     debug_assert_eq!(src16.len(), 16);
 
     #[allow(clippy::cast_ptr_alignment)]
@@ -140,16 +856,19 @@ unsafe fn validate_chunk16_sse2(src16: &[u8], hex_base: usize) -> Result<(), Err
     let le_9 = _mm_cmplt_epi8(v, _mm_set1_epi8((b'9' + 1).cast_signed()));
     let is_digit = _mm_and_si128(ge_0, le_9);
 
-    let lower = _mm_or_si128(v, _mm_set1_epi8(0x20u8.cast_signed()));
+    // Accepted letters: (v | fold) in first..=first + 5.
+    let (fold, first) = crate::validate::letter_range(case);
+    let folded = _mm_or_si128(v, _mm_set1_epi8(fold.cast_signed()));
 
-    let ge_a = _mm_cmpgt_epi8(lower, _mm_set1_epi8((b'a' - 1).cast_signed()));
-    let le_f = _mm_cmplt_epi8(lower, _mm_set1_epi8((b'f' + 1).cast_signed()));
+    let ge_a = _mm_cmpgt_epi8(folded, _mm_set1_epi8((first - 1).cast_signed()));
+    let le_f = _mm_cmplt_epi8(folded, _mm_set1_epi8((first + 6).cast_signed()));
     let is_alpha = _mm_and_si128(ge_a, le_f);
 
     let valid = _mm_or_si128(is_digit, is_alpha);
+    // `movemask` only populates the low 16 bits, so "all lanes valid" is 0xFFFF.
     let mask = _mm_movemask_epi8(valid);
 
-    if mask != -1 {
+    if mask != 0xFFFF {
         let bad_lane = (!mask.cast_unsigned()).trailing_zeros() as usize;
         return Err(Error::InvalidByte {
             index: hex_base + bad_lane,
@@ -160,7 +879,36 @@ unsafe fn validate_chunk16_sse2(src16: &[u8], hex_base: usize) -> Result<(), Err
     Ok(())
 }
 
-#[cfg(target_arch = "x86_64")]
+#[cfg(all(target_arch = "x86_64", target_feature = "sse2"))]
+#[target_feature(enable = "sse2")]
+unsafe fn letter_case_chunk16_sse2(src16: &[u8]) -> u8 {
+    debug_assert_eq!(src16.len(), 16);
+
+    #[allow(clippy::cast_ptr_alignment)]
+    let v = _mm_loadu_si128(src16.as_ptr().cast::<__m128i>());
+
+    // Hex letters only: (v | 0x20) in 'a'..='f'. Bytes >= 0x80 are negative
+    // and fail the signed lower bound.
+    let lower = _mm_or_si128(v, _mm_set1_epi8(0x20u8.cast_signed()));
+    let ge_a = _mm_cmpgt_epi8(lower, _mm_set1_epi8((b'a' - 1).cast_signed()));
+    let le_f = _mm_cmplt_epi8(lower, _mm_set1_epi8((b'f' + 1).cast_signed()));
+    let is_alpha = _mm_and_si128(ge_a, le_f);
+
+    // Bit 0x20 set => lowercase letter.
+    let case_bit = _mm_set1_epi8(0x20u8.cast_signed());
+    let is_lower_bit = _mm_cmpeq_epi8(_mm_and_si128(v, case_bit), case_bit);
+
+    let mut flags = 0;
+    if _mm_movemask_epi8(_mm_and_si128(is_alpha, is_lower_bit)) != 0 {
+        flags |= HAS_LOWER;
+    }
+    if _mm_movemask_epi8(_mm_andnot_si128(is_lower_bit, is_alpha)) != 0 {
+        flags |= HAS_UPPER;
+    }
+    flags
+}
+
+#[cfg(all(target_arch = "x86_64", target_feature = "sse2"))]
 #[target_feature(enable = "sse2")]
 unsafe fn decode_chunk16_sse2(src16: &[u8], dst8: &mut [u8]) {
     debug_assert_eq!(src16.len(), 16);
@@ -173,7 +921,14 @@ unsafe fn decode_chunk16_sse2(src16: &[u8], dst8: &mut [u8]) {
 
     #[allow(clippy::cast_ptr_alignment)]
     let v = _mm_loadu_si128(src16.as_ptr().cast::<__m128i>());
+    #[allow(clippy::cast_ptr_alignment)]
+    _mm_storel_epi64(dst8.as_mut_ptr().cast::<__m128i>(), decode16_sse2(v));
+}
 
+/// 16 validated hex chars -> 8 bytes in the low half of the result.
+#[cfg(all(target_arch = "x86_64", target_feature = "sse2"))]
+#[target_feature(enable = "sse2")]
+fn decode16_sse2(v: __m128i) -> __m128i {
     // lower = v | 0x20 (ASCII case fold)
     let lower = _mm_or_si128(v, _mm_set1_epi8(0x20u8.cast_signed()));
 
@@ -196,14 +951,119 @@ unsafe fn decode_chunk16_sse2(src16: &[u8], dst8: &mut [u8]) {
     let low = _mm_and_si128(w, _mm_set1_epi16(0x00FFu16.cast_signed()));
     let high = _mm_srli_epi16(w, 8);
     let packed_words = _mm_or_si128(_mm_slli_epi16(low, 4), high);
-    let packed_bytes = _mm_packus_epi16(packed_words, _mm_setzero_si128());
+    _mm_packus_epi16(packed_words, _mm_setzero_si128())
+}
+
+/// Decode validated hex into a 16-byte-aligned `dst` (a multiple of 16
+/// bytes) with non-temporal stores, then fence.
+#[cfg(all(target_arch = "x86_64", target_feature = "sse2"))]
+#[target_feature(enable = "sse2")]
+unsafe fn decode_stream_sse2(src_hex: &[u8], dst: &mut [u8]) {
+    debug_assert_eq!(src_hex.len(), dst.len() * 2);
+    debug_assert!(dst.len().is_multiple_of(16));
+    debug_assert!(dst.is_empty() || dst.as_ptr().addr().is_multiple_of(16));
+
+    for (src32, out16) in src_hex.chunks_exact(32).zip(dst.chunks_exact_mut(16)) {
+        #[allow(clippy::cast_ptr_alignment)]
+        let lo = _mm_loadu_si128(src32.as_ptr().cast::<__m128i>());
+        #[allow(clippy::cast_ptr_alignment)]
+        let hi = _mm_loadu_si128(src32.as_ptr().add(16).cast::<__m128i>());
+        let bytes = _mm_unpacklo_epi64(decode16_sse2(lo), decode16_sse2(hi));
+        #[allow(clippy::cast_ptr_alignment)]
+        _mm_stream_si128(out16.as_mut_ptr().cast::<__m128i>(), bytes);
+    }
+    // Order the weakly-ordered streaming stores before any later store.
+    _mm_sfence();
+}
+
+#[cfg(all(target_arch = "x86_64", target_feature = "sse2"))]
+#[target_feature(enable = "sse2")]
+unsafe fn encode_chunk16_sse2(src16: &[u8], dst32: &mut [u8], lowercase: bool) {
+    debug_assert_eq!(src16.len(), 16);
+    debug_assert_eq!(dst32.len(), 32);
+
+    // SAFETY:
+    // - Caller guarantees 16-byte input and 32-byte output slices.
+    // - Unaligned loads/stores via *_loadu_* / *_storeu_* intrinsics.
+
+    #[allow(clippy::cast_ptr_alignment)]
+    let v = _mm_loadu_si128(src16.as_ptr().cast::<__m128i>());
+    let (a0, a1) = encode16_sse2(v, lowercase);
+
     #[allow(clippy::cast_ptr_alignment)]
-    _mm_storel_epi64(dst8.as_mut_ptr().cast::<__m128i>(), packed_bytes);
+    _mm_storeu_si128(dst32.as_mut_ptr().cast::<__m128i>(), a0);
+    #[allow(clippy::cast_ptr_alignment)]
+    _mm_storeu_si128(dst32.as_mut_ptr().add(16).cast::<__m128i>(), a1);
 }
 
-#[cfg(target_arch = "aarch64")]
+/// 16 bytes -> 32 hex chars, as two 16-char halves in output order.
+#[cfg(all(target_arch = "x86_64", target_feature = "sse2"))]
+#[target_feature(enable = "sse2")]
+fn encode16_sse2(v: __m128i, lowercase: bool) -> (__m128i, __m128i) {
+    // Split nibbles. There is no 8-bit shift, so shift 16-bit words and mask.
+    let mask_lo = _mm_set1_epi8(0x0Fu8.cast_signed());
+    let hi = _mm_and_si128(_mm_srli_epi16(v, 4), mask_lo);
+    let lo = _mm_and_si128(v, mask_lo);
+
+    // Interleave: hi0 lo0 hi1 lo1 ... (first 8 bytes), then the last 8 bytes.
+    let n0 = _mm_unpacklo_epi8(hi, lo);
+    let n1 = _mm_unpackhi_epi8(hi, lo);
+
+    // ascii = n + '0' + (n > 9 ? letter_off : 0)
+    // SSE2 has no byte shuffle (pshufb is SSSE3), so the alphabet is applied
+    // arithmetically; it yields exactly the scalar alphabet for 0..=15.
+    let letter_off = if lowercase {
+        b'a' - b'0' - 10
+    } else {
+        b'A' - b'0' - 10
+    };
+    let nine = _mm_set1_epi8(9);
+    let zero = _mm_set1_epi8(b'0'.cast_signed());
+    let off = _mm_set1_epi8(letter_off.cast_signed());
+
+    let a0 = _mm_add_epi8(
+        _mm_add_epi8(n0, zero),
+        _mm_and_si128(_mm_cmpgt_epi8(n0, nine), off),
+    );
+    let a1 = _mm_add_epi8(
+        _mm_add_epi8(n1, zero),
+        _mm_and_si128(_mm_cmpgt_epi8(n1, nine), off),
+    );
+    (a0, a1)
+}
+
+/// Encode into a 16-byte-aligned `dst_hex` (a multiple of 32 bytes) with
+/// non-temporal stores, then fence.
+#[cfg(all(target_arch = "x86_64", target_feature = "sse2"))]
+#[target_feature(enable = "sse2")]
+unsafe fn encode_stream_sse2(src: &[u8], dst_hex: &mut [u8], lowercase: bool) {
+    debug_assert_eq!(dst_hex.len(), src.len() * 2);
+    debug_assert!(src.len().is_multiple_of(ENC_CHUNK_IN));
+    debug_assert!(dst_hex.is_empty() || dst_hex.as_ptr().addr().is_multiple_of(16));
+
+    for (src16, out32) in src
+        .chunks_exact(ENC_CHUNK_IN)
+        .zip(dst_hex.chunks_exact_mut(ENC_CHUNK_HEX))
+    {
+        #[allow(clippy::cast_ptr_alignment)]
+        let v = _mm_loadu_si128(src16.as_ptr().cast::<__m128i>());
+        let (a0, a1) = encode16_sse2(v, lowercase);
+        #[allow(clippy::cast_ptr_alignment)]
+        _mm_stream_si128(out32.as_mut_ptr().cast::<__m128i>(), a0);
+        #[allow(clippy::cast_ptr_alignment)]
+        _mm_stream_si128(out32.as_mut_ptr().add(16).cast::<__m128i>(), a1);
+    }
+    // Order the weakly-ordered streaming stores before any later store.
+    _mm_sfence();
+}
+
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
 #[target_feature(enable = "neon")]
-unsafe fn validate_chunk16_neon(src16: &[u8], hex_base: usize) -> Result<(), Error> {
+unsafe fn validate_chunk16_neon(
+    src16: &[u8],
+    hex_base: usize,
+    case: CasePolicy,
+) -> Result<(), Error> {
     debug_assert_eq!(src16.len(), 16);
 
     let v: uint8x16_t = vld1q_u8(src16.as_ptr());
@@ -212,10 +1072,12 @@ unsafe fn validate_chunk16_neon(src16: &[u8], hex_base: usize) -> Result<(), Err
     let le_9: uint8x16_t = vcleq_u8(v, vdupq_n_u8(b'9'));
     let is_digit: uint8x16_t = vandq_u8(ge_0, le_9);
 
-    let lower: uint8x16_t = vorrq_u8(v, vdupq_n_u8(0x20));
+    // Accepted letters: (v | fold) in first..=first + 5.
+    let (fold, first) = crate::validate::letter_range(case);
+    let folded: uint8x16_t = vorrq_u8(v, vdupq_n_u8(fold));
 
-    let ge_a: uint8x16_t = vcgeq_u8(lower, vdupq_n_u8(b'a'));
-    let le_f: uint8x16_t = vcleq_u8(lower, vdupq_n_u8(b'f'));
+    let ge_a: uint8x16_t = vcgeq_u8(folded, vdupq_n_u8(first));
+    let le_f: uint8x16_t = vcleq_u8(folded, vdupq_n_u8(first + 5));
     let is_alpha: uint8x16_t = vandq_u8(ge_a, le_f);
 
     let valid: uint8x16_t = vorrq_u8(is_digit, is_alpha);
@@ -237,7 +1099,33 @@ unsafe fn validate_chunk16_neon(src16: &[u8], hex_base: usize) -> Result<(), Err
     Ok(())
 }
 
-#[cfg(target_arch = "aarch64")]
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+#[target_feature(enable = "neon")]
+unsafe fn letter_case_chunk16_neon(src16: &[u8]) -> u8 {
+    debug_assert_eq!(src16.len(), 16);
+
+    let v: uint8x16_t = vld1q_u8(src16.as_ptr());
+
+    // Hex letters only: (v | 0x20) in 'a'..='f'.
+    let lower: uint8x16_t = vorrq_u8(v, vdupq_n_u8(0x20));
+    let ge_a: uint8x16_t = vcgeq_u8(lower, vdupq_n_u8(b'a'));
+    let le_f: uint8x16_t = vcleq_u8(lower, vdupq_n_u8(b'f'));
+    let is_alpha: uint8x16_t = vandq_u8(ge_a, le_f);
+
+    // Bit 0x20 set => lowercase letter.
+    let is_lower_bit: uint8x16_t = vtstq_u8(v, vdupq_n_u8(0x20));
+
+    let mut flags = 0;
+    if vmaxvq_u8(vandq_u8(is_alpha, is_lower_bit)) != 0 {
+        flags |= HAS_LOWER;
+    }
+    if vmaxvq_u8(vbicq_u8(is_alpha, is_lower_bit)) != 0 {
+        flags |= HAS_UPPER;
+    }
+    flags
+}
+
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
 #[target_feature(enable = "neon")]
 unsafe fn decode_chunk16_neon(src16: &[u8], dst8: &mut [u8]) {
     debug_assert_eq!(src16.len(), 16);
@@ -279,6 +1167,45 @@ unsafe fn decode_chunk16_neon(src16: &[u8], dst8: &mut [u8]) {
     let packed_bytes: uint8x8_t = vmovn_u16(packed_words);
     vst1_u8(dst8.as_mut_ptr(), packed_bytes);
 }
+
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+#[target_feature(enable = "neon")]
+unsafe fn encode_chunk16_neon(src16: &[u8], dst32: &mut [u8], lowercase: bool) {
+    debug_assert_eq!(src16.len(), 16);
+    debug_assert_eq!(dst32.len(), 32);
+
+    // SAFETY:
+    // - Caller guarantees 16-byte input and 32-byte output slices.
+    // - NEON loads/stores are used with valid pointers to slice memory.
+
+    let v: uint8x16_t = vld1q_u8(src16.as_ptr());
+    let (hi_ascii, lo_ascii) = nibbles_ascii_neon(v, lowercase);
+
+    // Interleave: hi0 lo0 hi1 lo1 ...
+    vst1q_u8(dst32.as_mut_ptr(), vzip1q_u8(hi_ascii, lo_ascii));
+    vst1q_u8(dst32.as_mut_ptr().add(16), vzip2q_u8(hi_ascii, lo_ascii));
+}
+
+/// 16 bytes -> (high-nibble chars, low-nibble chars), not yet interleaved.
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+#[target_feature(enable = "neon")]
+fn nibbles_ascii_neon(v: uint8x16_t, lowercase: bool) -> (uint8x16_t, uint8x16_t) {
+    let alphabet: &[u8; 16] = if lowercase {
+        b"0123456789abcdef"
+    } else {
+        b"0123456789ABCDEF"
+    };
+    // SAFETY: `alphabet` is 16 readable bytes.
+    let table: uint8x16_t = unsafe { vld1q_u8(alphabet.as_ptr()) };
+
+    // Split nibbles.
+    let hi: uint8x16_t = vshrq_n_u8(v, 4);
+    let lo: uint8x16_t = vandq_u8(v, vdupq_n_u8(0x0F));
+
+    // Map nibbles -> ASCII with a 16-entry table shuffle.
+    (vqtbl1q_u8(table, hi), vqtbl1q_u8(table, lo))
+}
+
 #[cfg(all(test, feature = "simd"))]
 #[path = "simd/tests.rs"]
 mod tests;
@@ -0,0 +1,144 @@
+//! AVX2 decode tier (`x86_64`, runtime-detected).
+//!
+//! Bulk input is processed 32 hex chars per iteration; the remainder goes
+//! through the SSE2 + scalar baseline. Validation and decoding are separate
+//! passes so callers keep the "no writes on error" contract.
+
+use super::{decode_base, validate_base};
+use crate::validate::{letter_range, CasePolicy};
+use crate::Error;
+
+use core::arch::x86_64::{
+    __m128i, __m256i, _mm256_add_epi8, _mm256_and_si256, _mm256_castsi256_si128, _mm256_cmpgt_epi8,
+    _mm256_loadu_si256, _mm256_maddubs_epi16, _mm256_movemask_epi8, _mm256_or_si256,
+    _mm256_packus_epi16, _mm256_permute4x64_epi64, _mm256_set1_epi16, _mm256_set1_epi8,
+    _mm_storeu_si128,
+};
+
+const CHUNK_HEX: usize = 32;
+const CHUNK_OUT: usize = 16;
+
+/// Validate with 32-char AVX2 chunks, then the SSE2 + scalar baseline.
+/// Only letters of `case` are accepted. Reported indices are offset by
+/// `hex_base`.
+///
+/// # Safety
+///
+/// The CPU must support AVX2.
+#[target_feature(enable = "avx2")]
+pub(super) unsafe fn validate_avx2(
+    src_hex: &[u8],
+    hex_base: usize,
+    case: CasePolicy,
+) -> Result<(), Error> {
+    let iters = src_hex.len() / CHUNK_HEX;
+    for i in 0..iters {
+        let hex_off = i * CHUNK_HEX;
+        validate_chunk32_avx2(
+            &src_hex[hex_off..hex_off + CHUNK_HEX],
+            hex_base + hex_off,
+            case,
+        )?;
+    }
+    let rest_hex_start = iters * CHUNK_HEX;
+    validate_base(&src_hex[rest_hex_start..], hex_base + rest_hex_start, case)
+}
+
+/// Decode already-validated input with 32-char AVX2 chunks, then the SSE2 +
+/// scalar baseline.
+///
+/// # Safety
+///
+/// The CPU must support AVX2.
+#[target_feature(enable = "avx2")]
+pub(super) unsafe fn decode_avx2(src_hex: &[u8], dst: &mut [u8]) {
+    debug_assert_eq!(src_hex.len(), dst.len() * 2);
+
+    let iters = dst.len() / CHUNK_OUT;
+    for i in 0..iters {
+        let hex_off = i * CHUNK_HEX;
+        let out_off = i * CHUNK_OUT;
+        decode_chunk32_avx2(
+            &src_hex[hex_off..hex_off + CHUNK_HEX],
+            &mut dst[out_off..out_off + CHUNK_OUT],
+        );
+    }
+    decode_base(&src_hex[iters * CHUNK_HEX..], &mut dst[iters * CHUNK_OUT..]);
+}
+
+#[target_feature(enable = "avx2")]
+pub(super) unsafe fn validate_chunk32_avx2(
+    src32: &[u8],
+    hex_base: usize,
+    case: CasePolicy,
+) -> Result<(), Error> {
+    debug_assert_eq!(src32.len(), 32);
+
+    #[allow(clippy::cast_ptr_alignment)]
+    let v = _mm256_loadu_si256(src32.as_ptr().cast::<__m256i>());
+
+    // AVX2 has no signed "less than", so `x < c` is written as `c > x`.
+    let ge_0 = _mm256_cmpgt_epi8(v, _mm256_set1_epi8((b'0' - 1).cast_signed()));
+    let le_9 = _mm256_cmpgt_epi8(_mm256_set1_epi8((b'9' + 1).cast_signed()), v);
+    let is_digit = _mm256_and_si256(ge_0, le_9);
+
+    // Accepted letters: (v | fold) in first..=first + 5.
+    let (fold, first) = letter_range(case);
+    let folded = _mm256_or_si256(v, _mm256_set1_epi8(fold.cast_signed()));
+
+    let ge_a = _mm256_cmpgt_epi8(folded, _mm256_set1_epi8((first - 1).cast_signed()));
+    let le_f = _mm256_cmpgt_epi8(_mm256_set1_epi8((first + 6).cast_signed()), folded);
+    let is_alpha = _mm256_and_si256(ge_a, le_f);
+
+    let valid = _mm256_or_si256(is_digit, is_alpha);
+    // 32 lanes fill the whole `i32`, so "all lanes valid" is -1 here.
+    let mask = _mm256_movemask_epi8(valid);
+
+    if mask != -1 {
+        let bad_lane = (!mask.cast_unsigned()).trailing_zeros() as usize;
+        return Err(Error::InvalidByte {
+            index: hex_base + bad_lane,
+            byte: src32[bad_lane],
+        });
+    }
+
+    Ok(())
+}
+
+#[target_feature(enable = "avx2")]
+pub(super) unsafe fn decode_chunk32_avx2(src32: &[u8], dst16: &mut [u8]) {
+    debug_assert_eq!(src32.len(), 32);
+    debug_assert_eq!(dst16.len(), 16);
+
+    // SAFETY:
+    // - Caller guarantees 32-byte input and 16-byte output slices.
+    // - Unaligned loads/stores via *_loadu_* / *_storeu_* intrinsics.
+
+    #[allow(clippy::cast_ptr_alignment)]
+    let v = _mm256_loadu_si256(src32.as_ptr().cast::<__m256i>());
+
+    // lower = v | 0x20 (ASCII case fold)
+    let lower = _mm256_or_si256(v, _mm256_set1_epi8(0x20u8.cast_signed()));
+    // Input is validated, so any byte above '9' is a letter.
+    let is_alpha = _mm256_cmpgt_epi8(lower, _mm256_set1_epi8(b'9'.cast_signed()));
+
+    // nibble = (v & 0x0F) + (is_alpha ? 9 : 0)
+    let low_nibble = _mm256_and_si256(v, _mm256_set1_epi8(0x0Fu8.cast_signed()));
+    let add = _mm256_and_si256(is_alpha, _mm256_set1_epi8(9i8));
+    let nibbles = _mm256_add_epi8(low_nibble, add);
+
+    // Multiply-add adjacent pairs: word = n_even * 16 + n_odd.
+    // The i16 multiplier 0x0110 is bytes [0x10, 0x01] in little-endian order.
+    let words = _mm256_maddubs_epi16(nibbles, _mm256_set1_epi16(0x0110));
+
+    // `packus` works per 128-bit lane: [w0 | w0 | w1 | w1] as 64-bit quarters.
+    // Gather quarters 0 and 2 into the low half.
+    let packed = _mm256_packus_epi16(words, words);
+    let ordered = _mm256_permute4x64_epi64(packed, 0b00_00_10_00);
+
+    #[allow(clippy::cast_ptr_alignment)]
+    _mm_storeu_si128(
+        dst16.as_mut_ptr().cast::<__m128i>(),
+        _mm256_castsi256_si128(ordered),
+    );
+}
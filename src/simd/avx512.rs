@@ -0,0 +1,163 @@
+//! AVX-512 (BW + VBMI) decode tier (`x86_64`, runtime-detected).
+//!
+//! 64 hex chars per iteration. `vpermi2b` maps every ASCII byte through a
+//! 128-entry nibble table in one instruction, and validation is a pair of
+//! mask-register compares, so the first invalid lane is a `trailing_zeros`
+//! away. The remainder goes through the AVX2 tier.
+
+use super::avx2::{decode_avx2, validate_avx2};
+use crate::validate::CasePolicy;
+use crate::Error;
+
+use core::arch::x86_64::{
+    __m256i, __m512i, _mm256_storeu_si256, _mm512_cmpeq_epi8_mask, _mm512_cvtepi16_epi8,
+    _mm512_loadu_si512, _mm512_maddubs_epi16, _mm512_movepi8_mask, _mm512_permutex2var_epi8,
+    _mm512_set1_epi16, _mm512_set1_epi8,
+};
+
+const CHUNK_HEX: usize = 64;
+const CHUNK_OUT: usize = 32;
+
+// ASCII 0x00..=0x7F -> nibble (0..=15) or 0xFF for invalid, split into the
+// two 64-byte halves `vpermi2b` selects between with index bit 6. The
+// case-strict tables leave the other case's letters invalid.
+pub(super) static NIBBLE_LUT: [u8; 128] = make_nibble_lut(CasePolicy::Any);
+static NIBBLE_LUT_LOWER: [u8; 128] = make_nibble_lut(CasePolicy::Lower);
+static NIBBLE_LUT_UPPER: [u8; 128] = make_nibble_lut(CasePolicy::Upper);
+
+const fn make_nibble_lut(case: CasePolicy) -> [u8; 128] {
+    let mut t = [0xFFu8; 128];
+    let mut i = 0u8;
+    while i < 10 {
+        t[(b'0' + i) as usize] = i;
+        i += 1;
+    }
+    let mut i = 0u8;
+    while i < 6 {
+        if !matches!(case, CasePolicy::Upper) {
+            t[(b'a' + i) as usize] = 10 + i;
+        }
+        if !matches!(case, CasePolicy::Lower) {
+            t[(b'A' + i) as usize] = 10 + i;
+        }
+        i += 1;
+    }
+    t
+}
+
+fn nibble_lut(case: CasePolicy) -> &'static [u8; 128] {
+    match case {
+        CasePolicy::Any => &NIBBLE_LUT,
+        CasePolicy::Lower => &NIBBLE_LUT_LOWER,
+        CasePolicy::Upper => &NIBBLE_LUT_UPPER,
+    }
+}
+
+/// Validate with 64-char AVX-512 chunks, then the AVX2 tier.
+/// Only letters of `case` are accepted. Reported indices are offset by
+/// `hex_base`.
+///
+/// # Safety
+///
+/// The CPU must support AVX-512F, AVX-512BW, AVX-512VBMI and AVX2.
+#[target_feature(enable = "avx512f,avx512bw,avx512vbmi,avx2")]
+pub(super) unsafe fn validate_avx512(
+    src_hex: &[u8],
+    hex_base: usize,
+    case: CasePolicy,
+) -> Result<(), Error> {
+    let lut = nibble_lut(case);
+    let iters = src_hex.len() / CHUNK_HEX;
+    for i in 0..iters {
+        let hex_off = i * CHUNK_HEX;
+        validate_chunk64_avx512(
+            &src_hex[hex_off..hex_off + CHUNK_HEX],
+            hex_base + hex_off,
+            lut,
+        )?;
+    }
+    let rest_hex_start = iters * CHUNK_HEX;
+    validate_avx2(&src_hex[rest_hex_start..], hex_base + rest_hex_start, case)
+}
+
+/// Decode already-validated input with 64-char AVX-512 chunks, then the
+/// AVX2 tier.
+///
+/// # Safety
+///
+/// The CPU must support AVX-512F, AVX-512BW, AVX-512VBMI and AVX2.
+#[target_feature(enable = "avx512f,avx512bw,avx512vbmi,avx2")]
+pub(super) unsafe fn decode_avx512(src_hex: &[u8], dst: &mut [u8]) {
+    debug_assert_eq!(src_hex.len(), dst.len() * 2);
+
+    let iters = dst.len() / CHUNK_OUT;
+    for i in 0..iters {
+        let hex_off = i * CHUNK_HEX;
+        let out_off = i * CHUNK_OUT;
+        decode_chunk64_avx512(
+            &src_hex[hex_off..hex_off + CHUNK_HEX],
+            &mut dst[out_off..out_off + CHUNK_OUT],
+        );
+    }
+    decode_avx2(&src_hex[iters * CHUNK_HEX..], &mut dst[iters * CHUNK_OUT..]);
+}
+
+/// Map 64 ASCII bytes to nibbles through `lut`. Returns the nibbles and a
+/// mask of invalid lanes (not in `lut`, or non-ASCII).
+#[inline]
+#[target_feature(enable = "avx512f,avx512bw,avx512vbmi")]
+unsafe fn map_chunk64(src64: &[u8], lut: &[u8; 128]) -> (__m512i, u64) {
+    debug_assert_eq!(src64.len(), 64);
+
+    #[allow(clippy::cast_ptr_alignment)]
+    let v = _mm512_loadu_si512(src64.as_ptr().cast::<__m512i>());
+    #[allow(clippy::cast_ptr_alignment)]
+    let lut_lo = _mm512_loadu_si512(lut.as_ptr().cast::<__m512i>());
+    #[allow(clippy::cast_ptr_alignment)]
+    let lut_hi = _mm512_loadu_si512(lut.as_ptr().add(64).cast::<__m512i>());
+
+    // Index bits 0..=6 select one of 128 table entries; bit 7 is ignored, so
+    // bytes >= 0x80 are flagged separately via their sign bit.
+    let nibbles = _mm512_permutex2var_epi8(lut_lo, v, lut_hi);
+    let invalid = _mm512_cmpeq_epi8_mask(nibbles, _mm512_set1_epi8(-1)) | _mm512_movepi8_mask(v);
+
+    (nibbles, invalid)
+}
+
+#[target_feature(enable = "avx512f,avx512bw,avx512vbmi")]
+pub(super) unsafe fn validate_chunk64_avx512(
+    src64: &[u8],
+    hex_base: usize,
+    lut: &[u8; 128],
+) -> Result<(), Error> {
+    let (_, invalid) = map_chunk64(src64, lut);
+
+    if invalid != 0 {
+        let bad_lane = invalid.trailing_zeros() as usize;
+        return Err(Error::InvalidByte {
+            index: hex_base + bad_lane,
+            byte: src64[bad_lane],
+        });
+    }
+
+    Ok(())
+}
+
+#[target_feature(enable = "avx512f,avx512bw,avx512vbmi")]
+pub(super) unsafe fn decode_chunk64_avx512(src64: &[u8], dst32: &mut [u8]) {
+    debug_assert_eq!(dst32.len(), 32);
+
+    // SAFETY:
+    // - Caller guarantees 64-byte input and 32-byte output slices.
+    // - Unaligned loads/stores via *_loadu_* / *_storeu_* intrinsics.
+
+    let (nibbles, _) = map_chunk64(src64, &NIBBLE_LUT);
+
+    // word = n_even * 16 + n_odd (multiplier bytes [0x10, 0x01]), then
+    // truncate the 32 words to bytes.
+    let words = _mm512_maddubs_epi16(nibbles, _mm512_set1_epi16(0x0110));
+    let packed = _mm512_cvtepi16_epi8(words);
+
+    #[allow(clippy::cast_ptr_alignment)]
+    _mm256_storeu_si256(dst32.as_mut_ptr().cast::<__m256i>(), packed);
+}
@@ -0,0 +1,356 @@
+//! Separated encode kernels: one-byte separator after every 1, 2, 4 or 8
+//! bytes (`de:ad:be:ef`, `deadbeef 01020304`).
+//!
+//! 16 input bytes -> 32 hex chars plus `16 / group` separators per
+//! iteration. The regular 16-byte encoder produces the hex chars, which are
+//! then spread into place:
+//!
+//! - `x86_64`, AVX2 tier and up: `pshufb` with a precomputed index table per
+//!   group size. SSSE3 is not part of the SSE2 baseline, but every AVX2 CPU
+//!   has it.
+//! - `x86_64`, SSE2 only: the hex registers are split into 64-bit lanes
+//!   (`punpckhqdq` + `movq`) and written out group by group.
+//! - aarch64: `vst3q_u8` for single bytes, `tbl` with the same index tables
+//!   for larger groups.
+
+#[cfg(all(target_arch = "x86_64", target_feature = "sse2"))]
+use super::{encode16_sse2, level, Level};
+
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+use super::{nibbles_ascii_neon, use_base_kernel};
+
+#[cfg(all(target_arch = "x86_64", target_feature = "sse2"))]
+use core::arch::x86_64::{
+    __m128i, _mm_and_si128, _mm_cvtsi128_si64, _mm_loadu_si128, _mm_or_si128, _mm_set1_epi8,
+    _mm_shuffle_epi8, _mm_storeu_si128, _mm_unpackhi_epi64,
+};
+
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+use core::arch::aarch64::{
+    uint8x16_t, uint8x16x2_t, uint8x16x3_t, vandq_u8, vdupq_n_u8, vld1q_u8, vorrq_u8, vqtbl2q_u8,
+    vst1q_u8, vst3q_u8, vzip1q_u8, vzip2q_u8,
+};
+
+const CHUNK_IN: usize = 16;
+
+/// Output chars per 16-byte chunk: the hex chars plus one separator after
+/// every group (including the last, which the caller relies on).
+#[cfg(any(
+    all(target_arch = "x86_64", target_feature = "sse2"),
+    all(target_arch = "aarch64", target_feature = "neon")
+))]
+const fn chunk_out(group: usize) -> usize {
+    2 * CHUNK_IN + CHUNK_IN / group
+}
+
+/// Encode the leading whole 16-byte chunks of `src` with `sep` after every
+/// `group` bytes and return how many input bytes were done (0 if no kernel
+/// is available for this group size or CPU).
+///
+/// Every chunk also writes the separator after its last group, so the chunk
+/// holding the final input byte is always left to the caller; `dst` must be
+/// sized for the whole input (`encoded_len_separated(src.len(), 1, group)`).
+pub(crate) fn encode_group_sep_simd(
+    src: &[u8],
+    dst: &mut [u8],
+    sep: u8,
+    group: usize,
+    lowercase: bool,
+) -> usize {
+    let iters = src.len().saturating_sub(1) / CHUNK_IN;
+
+    #[cfg(all(target_arch = "x86_64", target_feature = "sse2"))]
+    {
+        let Some(tables) = spread_tables(group) else {
+            return 0;
+        };
+        debug_assert!(dst.len() >= iters * chunk_out(group));
+        match level() {
+            Level::Scalar => return 0,
+            Level::Base => {
+                // SAFETY: SSE2 is baseline on x86_64.
+                unsafe {
+                    match group {
+                        1 => encode_group_sep_sse2::<1>(src, dst, sep, lowercase, iters),
+                        2 => encode_group_sep_sse2::<2>(src, dst, sep, lowercase, iters),
+                        4 => encode_group_sep_sse2::<4>(src, dst, sep, lowercase, iters),
+                        _ => encode_group_sep_sse2::<8>(src, dst, sep, lowercase, iters),
+                    }
+                }
+            }
+            _ => {
+                // SAFETY: the AVX2 tier is only selected on CPUs with AVX2,
+                // which all support SSSE3.
+                unsafe { encode_group_sep_ssse3(src, dst, sep, tables, lowercase, iters) };
+            }
+        }
+        iters * CHUNK_IN
+    }
+
+    #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+    {
+        let Some(tables) = spread_tables(group) else {
+            return 0;
+        };
+        if !use_base_kernel() {
+            return 0;
+        }
+        debug_assert!(dst.len() >= iters * chunk_out(group));
+        // SAFETY: NEON is baseline on aarch64.
+        unsafe {
+            if group == 1 {
+                encode_byte_sep_neon(src, dst, sep, lowercase, iters);
+            } else {
+                encode_group_sep_neon(src, dst, sep, tables, lowercase, iters);
+            }
+        }
+        iters * CHUNK_IN
+    }
+
+    #[cfg(not(any(
+        all(target_arch = "x86_64", target_feature = "sse2"),
+        all(target_arch = "aarch64", target_feature = "neon")
+    )))]
+    {
+        let _ = (src, dst, sep, group, lowercase, iters);
+        0
+    }
+}
+
+// ── Index tables (pshufb / tbl) ────────────────────────────────────────────
+
+// Marks a separator slot. `pshufb` zeroes lanes whose index has the high
+// bit set, and `tbl` zeroes lanes whose index is out of range.
+#[cfg(any(
+    all(target_arch = "x86_64", target_feature = "sse2"),
+    all(target_arch = "aarch64", target_feature = "neon")
+))]
+const SEP_SLOT: u8 = 0x80;
+
+/// Per-chunk shuffle tables for one group size, per 16-char output register.
+#[cfg(any(
+    all(target_arch = "x86_64", target_feature = "sse2"),
+    all(target_arch = "aarch64", target_feature = "neon")
+))]
+pub(super) struct Spread {
+    /// Output slot -> hex char index (0..32), or `SEP_SLOT`.
+    idx: [[u8; 16]; 3],
+    /// Output slot -> index within the low hex register, or `SEP_SLOT`.
+    lo: [[u8; 16]; 3],
+    /// Output slot -> index within the high hex register, or `SEP_SLOT`.
+    hi: [[u8; 16]; 3],
+    /// `0xFF` at separator slots.
+    sep: [[u8; 16]; 3],
+}
+
+#[cfg(any(
+    all(target_arch = "x86_64", target_feature = "sse2"),
+    all(target_arch = "aarch64", target_feature = "neon")
+))]
+const fn make_spread(group: usize) -> Spread {
+    let mut t = Spread {
+        idx: [[SEP_SLOT; 16]; 3],
+        lo: [[SEP_SLOT; 16]; 3],
+        hi: [[SEP_SLOT; 16]; 3],
+        sep: [[0; 16]; 3],
+    };
+    let stride = 2 * group + 1;
+    let mut j = 0;
+    while j < chunk_out(group) {
+        let (g, slot) = (j / stride, j % stride);
+        let (k, lane) = (j / 16, j % 16);
+        if slot < 2 * group {
+            // Hex char indices are below 32.
+            #[allow(clippy::cast_possible_truncation)]
+            let hex = (2 * group * g + slot) as u8;
+            t.idx[k][lane] = hex;
+            if hex < 16 {
+                t.lo[k][lane] = hex;
+            } else {
+                t.hi[k][lane] = hex - 16;
+            }
+        } else {
+            t.sep[k][lane] = 0xFF;
+        }
+        j += 1;
+    }
+    t
+}
+
+#[cfg(any(
+    all(target_arch = "x86_64", target_feature = "sse2"),
+    all(target_arch = "aarch64", target_feature = "neon")
+))]
+static SPREAD: [Spread; 4] = [
+    make_spread(1),
+    make_spread(2),
+    make_spread(4),
+    make_spread(8),
+];
+
+/// Tables for `group`, or `None` if there is no kernel for it.
+#[cfg(any(
+    all(target_arch = "x86_64", target_feature = "sse2"),
+    all(target_arch = "aarch64", target_feature = "neon")
+))]
+#[inline]
+pub(super) fn spread_tables(group: usize) -> Option<&'static Spread> {
+    match group {
+        1 => Some(&SPREAD[0]),
+        2 => Some(&SPREAD[1]),
+        4 => Some(&SPREAD[2]),
+        8 => Some(&SPREAD[3]),
+        _ => None,
+    }
+}
+
+// ── x86_64 ─────────────────────────────────────────────────────────────────
+
+/// `pshufb` kernel for every group size. The third output register is only
+/// partly used for groups above 1, so it goes through the stack.
+#[cfg(all(target_arch = "x86_64", target_feature = "sse2"))]
+#[target_feature(enable = "ssse3")]
+pub(super) unsafe fn encode_group_sep_ssse3(
+    src: &[u8],
+    dst: &mut [u8],
+    sep: u8,
+    tables: &Spread,
+    lowercase: bool,
+    iters: usize,
+) {
+    let out_len = 32 + (0..16).filter(|&l| tables.sep[2][l] != 0).count();
+    let sep_v = _mm_set1_epi8(sep.cast_signed());
+    let load = |t: &[u8; 16]| {
+        // SAFETY: `t` is 16 readable bytes.
+        #[allow(clippy::cast_ptr_alignment)]
+        unsafe {
+            _mm_loadu_si128(t.as_ptr().cast::<__m128i>())
+        }
+    };
+    let lo_idx = tables.lo.map(|t| load(&t));
+    let hi_idx = tables.hi.map(|t| load(&t));
+    let seps = tables.sep.map(|t| _mm_and_si128(load(&t), sep_v));
+
+    for i in 0..iters {
+        let src16 = &src[i * CHUNK_IN..(i + 1) * CHUNK_IN];
+        let dst_chunk = &mut dst[i * out_len..(i + 1) * out_len];
+
+        #[allow(clippy::cast_ptr_alignment)]
+        let v = _mm_loadu_si128(src16.as_ptr().cast::<__m128i>());
+        // Hex chars 0..16 and 16..32.
+        let (a0, a1) = encode16_sse2(v, lowercase);
+
+        let out = |k: usize| {
+            _mm_or_si128(
+                _mm_or_si128(
+                    _mm_shuffle_epi8(a0, lo_idx[k]),
+                    _mm_shuffle_epi8(a1, hi_idx[k]),
+                ),
+                seps[k],
+            )
+        };
+        #[allow(clippy::cast_ptr_alignment)]
+        {
+            _mm_storeu_si128(dst_chunk.as_mut_ptr().cast::<__m128i>(), out(0));
+            _mm_storeu_si128(dst_chunk.as_mut_ptr().add(16).cast::<__m128i>(), out(1));
+        }
+        if out_len == 48 {
+            #[allow(clippy::cast_ptr_alignment)]
+            _mm_storeu_si128(dst_chunk.as_mut_ptr().add(32).cast::<__m128i>(), out(2));
+        } else {
+            let mut tail = [0u8; 16];
+            #[allow(clippy::cast_ptr_alignment)]
+            _mm_storeu_si128(tail.as_mut_ptr().cast::<__m128i>(), out(2));
+            dst_chunk[32..].copy_from_slice(&tail[..out_len - 32]);
+        }
+    }
+}
+
+/// SSE2 kernel: no byte shuffle, so the hex chars leave the registers as
+/// four 64-bit lanes and each group is copied out with fixed-width stores.
+#[cfg(all(target_arch = "x86_64", target_feature = "sse2"))]
+#[target_feature(enable = "sse2")]
+pub(super) unsafe fn encode_group_sep_sse2<const G: usize>(
+    src: &[u8],
+    dst: &mut [u8],
+    sep: u8,
+    lowercase: bool,
+    iters: usize,
+) {
+    let out_len = chunk_out(G);
+    for i in 0..iters {
+        let src16 = &src[i * CHUNK_IN..(i + 1) * CHUNK_IN];
+        let dst_chunk = &mut dst[i * out_len..(i + 1) * out_len];
+
+        #[allow(clippy::cast_ptr_alignment)]
+        let v = _mm_loadu_si128(src16.as_ptr().cast::<__m128i>());
+        let (a0, a1) = encode16_sse2(v, lowercase);
+        let lanes = [
+            _mm_cvtsi128_si64(a0),
+            _mm_cvtsi128_si64(_mm_unpackhi_epi64(a0, a0)),
+            _mm_cvtsi128_si64(a1),
+            _mm_cvtsi128_si64(_mm_unpackhi_epi64(a1, a1)),
+        ]
+        .map(|w| w.cast_unsigned().to_le_bytes());
+        let hex = lanes.as_flattened();
+
+        for (g, slot) in hex
+            .chunks_exact(2 * G)
+            .zip(dst_chunk.chunks_exact_mut(2 * G + 1))
+        {
+            slot[..2 * G].copy_from_slice(g);
+            slot[2 * G] = sep;
+        }
+    }
+}
+
+// ── aarch64 ────────────────────────────────────────────────────────────────
+
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+#[target_feature(enable = "neon")]
+unsafe fn encode_byte_sep_neon(src: &[u8], dst: &mut [u8], sep: u8, lowercase: bool, iters: usize) {
+    let sep_v = vdupq_n_u8(sep);
+    for i in 0..iters {
+        let src16 = &src[i * CHUNK_IN..(i + 1) * CHUNK_IN];
+        let dst48 = &mut dst[i * 48..(i + 1) * 48];
+
+        let (hi, lo) = nibbles_ascii_neon(vld1q_u8(src16.as_ptr()), lowercase);
+        // Three-way interleave: hi0 lo0 sep hi1 lo1 sep ...
+        vst3q_u8(dst48.as_mut_ptr(), uint8x16x3_t(hi, lo, sep_v));
+    }
+}
+
+/// `tbl` kernel for groups above 1; see [`encode_group_sep_ssse3`].
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+#[target_feature(enable = "neon")]
+unsafe fn encode_group_sep_neon(
+    src: &[u8],
+    dst: &mut [u8],
+    sep: u8,
+    tables: &Spread,
+    lowercase: bool,
+    iters: usize,
+) {
+    let out_len = 32 + (0..16).filter(|&l| tables.sep[2][l] != 0).count();
+    let sep_v = vdupq_n_u8(sep);
+    // SAFETY: each table row is 16 readable bytes.
+    let idx: [uint8x16_t; 3] = tables.idx.map(|t| unsafe { vld1q_u8(t.as_ptr()) });
+    let seps: [uint8x16_t; 3] = tables
+        .sep
+        .map(|t| vandq_u8(unsafe { vld1q_u8(t.as_ptr()) }, sep_v));
+
+    for i in 0..iters {
+        let src16 = &src[i * CHUNK_IN..(i + 1) * CHUNK_IN];
+        let dst_chunk = &mut dst[i * out_len..(i + 1) * out_len];
+
+        let (hi, lo) = nibbles_ascii_neon(vld1q_u8(src16.as_ptr()), lowercase);
+        let hex = uint8x16x2_t(vzip1q_u8(hi, lo), vzip2q_u8(hi, lo));
+        let out = |k: usize| vorrq_u8(vqtbl2q_u8(hex, idx[k]), seps[k]);
+
+        vst1q_u8(dst_chunk.as_mut_ptr(), out(0));
+        vst1q_u8(dst_chunk.as_mut_ptr().add(16), out(1));
+        let mut tail = [0u8; 16];
+        vst1q_u8(tail.as_mut_ptr(), out(2));
+        dst_chunk[32..].copy_from_slice(&tail[..out_len - 32]);
+    }
+}
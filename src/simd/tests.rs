@@ -325,3 +325,717 @@ fn test_idempotent() {
     let second = decode(hex).unwrap();
     assert_eq!(first, second);
 }
+
+// ── encode: SIMD vs scalar alphabet ────────────────────────────────────────
+
+fn encode(src: &[u8], lowercase: bool) -> Vec<u8> {
+    let mut dst = std::vec![0u8; src.len() * 2];
+    encode_to_slice_simd(src, &mut dst, lowercase);
+    dst
+}
+
+fn encode_ref(src: &[u8], lowercase: bool) -> Vec<u8> {
+    let mut dst = std::vec![0u8; src.len() * 2];
+    encode_scalar(src, &mut dst, lowercase);
+    dst
+}
+
+#[test]
+fn test_encode_empty() {
+    assert_eq!(encode(&[], true), b"");
+}
+
+#[test]
+fn test_encode_known_vectors() {
+    assert_eq!(encode(&[0xde, 0xad, 0xbe, 0xef], true), b"deadbeef");
+    assert_eq!(encode(&[0xde, 0xad, 0xbe, 0xef], false), b"DEADBEEF");
+}
+
+#[test]
+fn test_encode_exactly_one_chunk() {
+    // 16 input bytes → 32 hex chars, no scalar tail
+    let src: Vec<u8> = (0xf0u8..=0xff).collect();
+    assert_eq!(encode(&src, true), b"f0f1f2f3f4f5f6f7f8f9fafbfcfdfeff");
+    assert_eq!(encode(&src, false), b"F0F1F2F3F4F5F6F7F8F9FAFBFCFDFEFF");
+}
+
+#[test]
+fn test_encode_all_bytes_match_scalar() {
+    // 256 bytes → 16 full SIMD chunks covering every nibble in both positions.
+    let src: Vec<u8> = (0u8..=255).collect();
+    for lowercase in [true, false] {
+        assert_eq!(encode(&src, lowercase), encode_ref(&src, lowercase));
+    }
+}
+
+#[test]
+fn test_encode_chunk_boundaries_match_scalar() {
+    // Every length around 0..=3 chunks, so both the SIMD body and the tail are hit.
+    let src: Vec<u8> = (0u8..64).map(|i| i.wrapping_mul(37) ^ 0x5a).collect();
+    for len in 0..=src.len() {
+        for lowercase in [true, false] {
+            assert_eq!(
+                encode(&src[..len], lowercase),
+                encode_ref(&src[..len], lowercase),
+                "len={len} lowercase={lowercase}"
+            );
+        }
+    }
+}
+
+#[test]
+fn test_encode_roundtrip_through_simd_decode() {
+    let src: Vec<u8> = (0u8..=255).rev().collect();
+    for lowercase in [true, false] {
+        let hex = encode(&src, lowercase);
+        assert_eq!(decode(&hex).unwrap(), src);
+    }
+}
+
+// ── dispatch tiers: every kernel must agree with scalar ────────────────────
+
+fn decode_scalar_ref(hex: &[u8]) -> Result<Vec<u8>, Error> {
+    let mut dst = std::vec![0u8; hex.len() / 2];
+    crate::decode::decode_scalar(hex, &mut dst)?;
+    Ok(dst)
+}
+
+fn decode_base_path(hex: &[u8]) -> Result<Vec<u8>, Error> {
+    let mut dst = std::vec![0u8; hex.len() / 2];
+    validate_base(hex, 0, CasePolicy::Any)?;
+    decode_base(hex, &mut dst);
+    Ok(dst)
+}
+
+fn tier_vectors() -> Vec<Vec<u8>> {
+    // Valid mixed-case inputs at every length up to four 32-char AVX2 chunks + tail.
+    let src: Vec<u8> = (0u8..=255).map(|i| i.wrapping_mul(29) ^ 0xa5).collect();
+    let mut hex = std::vec![0u8; src.len() * 2];
+    crate::encode::encode_scalar(&src, &mut hex, true);
+    for (i, c) in hex.iter_mut().enumerate() {
+        if i % 3 == 0 {
+            c.make_ascii_uppercase();
+        }
+    }
+    (0..=70).map(|n| hex[..n * 2].to_vec()).collect()
+}
+
+#[test]
+fn test_level_is_cached_and_backend_can_be_pinned() {
+    // The only test that changes the global backend. Other tests may run
+    // meanwhile; results do not depend on the backend, so they still pass.
+    let detected = backend();
+    assert_ne!(LEVEL.load(Ordering::Relaxed), 0);
+
+    assert!(set_backend(Backend::Scalar));
+    assert_eq!(backend(), Backend::Scalar);
+    let hex = b"00112233445566778899aabbccddeeffDEADBEEF";
+    assert_eq!(decode(hex), decode_scalar_ref(hex));
+    assert_eq!(encode(&[0xab; 40], true), encode_ref(&[0xab; 40], true));
+    assert_eq!(
+        decode(b"00112233445566778899aabbccddeeffDEADBEEz"),
+        decode_scalar_ref(b"00112233445566778899aabbccddeeffDEADBEEz")
+    );
+
+    #[cfg(all(target_arch = "x86_64", target_feature = "sse2"))]
+    {
+        assert!(!set_backend(Backend::Neon));
+        assert!(set_backend(Backend::Sse2));
+        assert_eq!(backend(), Backend::Sse2);
+        if detected != Backend::Avx512 {
+            assert!(!set_backend(Backend::Avx512));
+        }
+    }
+    #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+    {
+        assert!(!set_backend(Backend::Sse2));
+        assert!(set_backend(Backend::Neon));
+        assert_eq!(backend(), Backend::Neon);
+    }
+
+    assert!(set_backend(detected));
+    assert_eq!(backend(), detected);
+}
+
+#[test]
+fn test_pin_before_first_use_is_kept() {
+    // A fresh cell stands in for `LEVEL` before any codec call.
+    let cell = AtomicU8::new(0);
+    assert!(set_backend_in(&cell, Backend::Scalar));
+    assert_eq!(level_in(&cell), Level::Scalar);
+    assert_eq!(cell.load(Ordering::Relaxed), Level::Scalar as u8);
+
+    // Without a pin, first use detects and caches the hardware level
+    // (`FAST_HEX_LITE_BACKEND` can only lower it).
+    let cell = AtomicU8::new(0);
+    let detected = level_in(&cell);
+    assert!(detected as u8 <= hardware_level() as u8);
+    assert_eq!(cell.load(Ordering::Relaxed), detected as u8);
+
+    // A pin after first use replaces the detected level.
+    assert!(set_backend_in(&cell, Backend::Scalar));
+    assert_eq!(level_in(&cell), Level::Scalar);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_backend_env_names() {
+    assert_eq!(Backend::from_name("scalar"), Some(Backend::Scalar));
+    assert_eq!(Backend::from_name(" AVX2\n"), Some(Backend::Avx2));
+    assert_eq!(Backend::from_name("Avx512"), Some(Backend::Avx512));
+    assert_eq!(Backend::from_name("sse4"), None);
+    assert_eq!(Backend::from_name(""), None);
+}
+
+#[test]
+fn test_base_tier_matches_scalar() {
+    for hex in tier_vectors() {
+        assert_eq!(decode_base_path(&hex), decode_scalar_ref(&hex));
+        // Inject a bad byte at every position.
+        for pos in 0..hex.len() {
+            let mut bad = hex.clone();
+            bad[pos] = b'g';
+            assert_eq!(
+                decode_base_path(&bad),
+                decode_scalar_ref(&bad),
+                "len={} pos={pos}",
+                hex.len()
+            );
+        }
+    }
+}
+
+#[cfg(all(target_arch = "x86_64", target_feature = "sse2"))]
+fn decode_avx2_path(hex: &[u8], dst: &mut [u8]) -> Result<(), Error> {
+    // SAFETY: callers check AVX2 support first.
+    unsafe {
+        avx2::validate_avx2(hex, 0, CasePolicy::Any)?;
+        avx2::decode_avx2(hex, dst);
+    }
+    Ok(())
+}
+
+#[cfg(all(target_arch = "x86_64", target_feature = "sse2"))]
+#[test]
+fn test_avx2_tier_matches_scalar() {
+    if !std::is_x86_feature_detected!("avx2") {
+        return;
+    }
+    let run = |hex: &[u8]| -> Result<Vec<u8>, Error> {
+        let mut dst = std::vec![0u8; hex.len() / 2];
+        decode_avx2_path(hex, &mut dst)?;
+        Ok(dst)
+    };
+    for hex in tier_vectors() {
+        assert_eq!(run(&hex), decode_scalar_ref(&hex));
+        for pos in 0..hex.len() {
+            let mut bad = hex.clone();
+            bad[pos] |= 0x80;
+            assert_eq!(
+                run(&bad),
+                decode_scalar_ref(&bad),
+                "len={} pos={pos}",
+                hex.len()
+            );
+        }
+    }
+}
+
+#[cfg(all(target_arch = "x86_64", target_feature = "sse2"))]
+#[test]
+fn test_avx2_first_of_several_bad_lanes() {
+    if !std::is_x86_feature_detected!("avx2") {
+        return;
+    }
+    let mut hex = *b"00112233445566778899aabbccddeeff00112233445566778899aabbccddeeff";
+    hex[40] = b'z';
+    hex[35] = b'!';
+    hex[50] = b' ';
+    let mut dst = [0xAAu8; 32];
+    let err = decode_avx2_path(&hex, &mut dst).unwrap_err();
+    assert_eq!(
+        err,
+        Error::InvalidByte {
+            index: 35,
+            byte: b'!'
+        }
+    );
+    assert!(dst.iter().all(|&b| b == 0xAA), "dst was partially written");
+}
+
+#[cfg(all(target_arch = "x86_64", target_feature = "sse2"))]
+fn has_avx512_tier() -> bool {
+    std::is_x86_feature_detected!("avx512f")
+        && std::is_x86_feature_detected!("avx512bw")
+        && std::is_x86_feature_detected!("avx512vbmi")
+        && std::is_x86_feature_detected!("avx2")
+}
+
+#[cfg(all(target_arch = "x86_64", target_feature = "sse2"))]
+fn decode_avx512_path(hex: &[u8], dst: &mut [u8]) -> Result<(), Error> {
+    // SAFETY: callers check `has_avx512_tier()` first.
+    unsafe {
+        avx512::validate_avx512(hex, 0, CasePolicy::Any)?;
+        avx512::decode_avx512(hex, dst);
+    }
+    Ok(())
+}
+
+#[cfg(all(target_arch = "x86_64", target_feature = "sse2"))]
+#[test]
+fn test_avx512_tier_matches_scalar() {
+    if !has_avx512_tier() {
+        return;
+    }
+    let run = |hex: &[u8]| -> Result<Vec<u8>, Error> {
+        let mut dst = std::vec![0u8; hex.len() / 2];
+        decode_avx512_path(hex, &mut dst)?;
+        Ok(dst)
+    };
+    for hex in tier_vectors() {
+        assert_eq!(run(&hex), decode_scalar_ref(&hex));
+        for pos in 0..hex.len() {
+            for bad_byte in [b'g', b'/', b':', b'@', b'`', 0x80 | hex[pos]] {
+                let mut bad = hex.clone();
+                bad[pos] = bad_byte;
+                assert_eq!(
+                    run(&bad),
+                    decode_scalar_ref(&bad),
+                    "len={} pos={pos} byte={bad_byte:#04x}",
+                    hex.len()
+                );
+            }
+        }
+    }
+}
+
+#[cfg(all(target_arch = "x86_64", target_feature = "sse2"))]
+#[test]
+fn test_avx512_first_of_several_bad_lanes() {
+    if !has_avx512_tier() {
+        return;
+    }
+    let mut hex = [b'a'; 128];
+    // Several bad lanes in the second 64-char chunk, plus a non-ASCII byte.
+    hex[127] = b'x';
+    hex[90] = 0xC3;
+    hex[77] = b'G';
+    hex[101] = b' ';
+    let mut dst = [0x55u8; 64];
+    let err = decode_avx512_path(&hex, &mut dst).unwrap_err();
+    assert_eq!(err, decode_scalar_ref(&hex).unwrap_err());
+    assert_eq!(
+        err,
+        Error::InvalidByte {
+            index: 77,
+            byte: b'G'
+        }
+    );
+    assert!(dst.iter().all(|&b| b == 0x55), "dst was partially written");
+}
+
+#[cfg(all(target_arch = "x86_64", target_feature = "sse2"))]
+#[test]
+fn test_avx512_nibble_lut_matches_scalar_table() {
+    for b in 0u8..0x80 {
+        let expected = crate::decode::unhex_byte(b).unwrap_or(0xFF);
+        assert_eq!(avx512::NIBBLE_LUT[b as usize], expected, "byte {b:#04x}");
+    }
+}
+
+// ── decode_in_place_simd ───────────────────────────────────────────────────
+
+fn decode_in_place_ref(hex: &[u8]) -> (Result<usize, Error>, Vec<u8>) {
+    let mut buf = hex.to_vec();
+    let res = crate::decode::decode_in_place_scalar(&mut buf);
+    (res, buf)
+}
+
+fn decode_in_place(hex: &[u8]) -> (Result<usize, Error>, Vec<u8>) {
+    let mut buf = hex.to_vec();
+    let res = decode_in_place_simd(&mut buf);
+    (res, buf)
+}
+
+#[test]
+fn test_in_place_empty() {
+    let mut buf: [u8; 0] = [];
+    assert_eq!(decode_in_place_simd(&mut buf), Ok(0));
+}
+
+#[test]
+fn test_in_place_known_vector() {
+    let mut buf = *b"00112233445566778899aabbccddeeff";
+    let n = decode_in_place_simd(&mut buf).unwrap();
+    assert_eq!(
+        &buf[..n],
+        &[
+            0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88, 0x99, 0xaa, 0xbb, 0xcc, 0xdd,
+            0xee, 0xff
+        ]
+    );
+    // The back half still holds the tail of the original hex.
+    assert_eq!(&buf[n..], b"8899aabbccddeeff");
+}
+
+#[test]
+fn test_in_place_matches_scalar_all_lengths() {
+    for hex in tier_vectors() {
+        assert_eq!(decode_in_place(&hex), decode_in_place_ref(&hex));
+    }
+}
+
+#[test]
+fn test_in_place_spans_several_blocks() {
+    // 3 full stack blocks + a partial one.
+    let src: Vec<u8> = (0..IN_PLACE_HEX * 2 + 77)
+        .map(|i| u8::try_from(i * 7 % 256).unwrap())
+        .collect();
+    let mut hex = std::vec![0u8; src.len() * 2];
+    crate::encode::encode_scalar(&src, &mut hex, false);
+    let (res, buf) = decode_in_place(&hex);
+    assert_eq!(res, Ok(src.len()));
+    assert_eq!(&buf[..src.len()], &src[..]);
+    assert_eq!((res, buf), decode_in_place_ref(&hex));
+}
+
+#[test]
+fn test_in_place_error_leaves_buffer_unchanged() {
+    let src: Vec<u8> = (0u8..=255).collect();
+    let mut hex = std::vec![0u8; src.len() * 2];
+    crate::encode::encode_scalar(&src, &mut hex, true);
+    for pos in [0, 1, 15, 16, 31, 32, 63, 64, 255, 256, 300, 511] {
+        let mut bad = hex.clone();
+        bad[pos] = b'#';
+        let (res, buf) = decode_in_place(&bad);
+        assert_eq!(
+            res,
+            Err(Error::InvalidByte {
+                index: pos,
+                byte: b'#'
+            })
+        );
+        assert_eq!(buf, bad, "buffer modified on error at {pos}");
+    }
+}
+
+// ── Fixed-size kernels ─────────────────────────────────────────────────────
+
+#[test]
+fn test_decode_fixed_base_matches_scalar() {
+    for n in [16, 20, 32, 64] {
+        let src: Vec<u8> = (0..n)
+            .map(|i| u8::try_from(i * 29 % 256).unwrap())
+            .collect();
+        for lowercase in [true, false] {
+            let hex = encode_ref(&src, lowercase);
+            let mut dst = std::vec![0u8; n];
+            decode_fixed_base(&hex, &mut dst).unwrap();
+            assert_eq!(dst, src, "n={n}");
+
+            for pos in 0..hex.len() {
+                let mut bad = hex.clone();
+                bad[pos] = b'G';
+                // A later bad byte, possibly inside the overlapping chunk.
+                let last = hex.len() - 1;
+                if pos != last {
+                    bad[last] = 0xFF;
+                }
+                let mut dst = std::vec![0xAAu8; n];
+                assert_eq!(
+                    decode_fixed_base(&bad, &mut dst),
+                    decode_scalar_ref(&bad).map(|_| ()),
+                    "n={n} pos={pos}"
+                );
+                assert!(dst.iter().all(|&b| b == 0xAA), "partial write n={n}");
+            }
+        }
+    }
+}
+
+#[test]
+fn test_encode_fixed_base_matches_scalar() {
+    for n in [16, 20, 32, 64] {
+        let src: Vec<u8> = (0..n)
+            .map(|i| u8::try_from(i * 29 % 256).unwrap())
+            .collect();
+        for lowercase in [true, false] {
+            let mut dst = std::vec![0u8; 2 * n];
+            encode_fixed_base(&src, &mut dst, lowercase);
+            assert_eq!(dst, encode_ref(&src, lowercase), "n={n}");
+        }
+    }
+}
+
+// ── Overlapping tails and the small-input kernel ───────────────────────────
+
+#[test]
+fn test_every_length_matches_scalar() {
+    // Covers < 16 (padded kernel), exact multiples, and every tail length
+    // after each tier's chunks.
+    let src: Vec<u8> = (0..80u8).map(|i| i.wrapping_mul(37)).collect();
+    let full = encode_ref(&src, false);
+    for len in (0..=full.len()).step_by(2) {
+        let hex = &full[..len];
+        assert_eq!(decode(hex), decode_scalar_ref(hex), "len={len}");
+    }
+}
+
+#[test]
+fn test_every_length_error_in_every_position() {
+    let full = encode_ref(&(0..72u8).collect::<Vec<_>>(), true);
+    for len in (2..=full.len()).step_by(2) {
+        for pos in 0..len {
+            let mut bad = full[..len].to_vec();
+            bad[pos] = b'g';
+            let mut dst = std::vec![0xAAu8; len / 2];
+            assert_eq!(
+                decode_to_slice_simd(&bad, &mut dst),
+                decode_scalar_ref(&bad).map(|v| v.len()),
+                "len={len} pos={pos}"
+            );
+            assert!(dst.iter().all(|&b| b == 0xAA), "partial write len={len}");
+        }
+    }
+}
+
+#[test]
+fn test_validate_simd_odd_lengths_match_scalar() {
+    // `classify` validates odd-length digit runs; the overlap and padding
+    // must not depend on pair alignment.
+    let full: Vec<u8> = b"0123456789abcdefABCDEF".repeat(3);
+    for len in 0..=full.len() {
+        for pos in 0..len {
+            let mut bad = full[..len].to_vec();
+            bad[pos] = b'!';
+            assert_eq!(
+                validate_simd(&bad, 5),
+                validate_hex_scalar(&bad, 5),
+                "len={len} pos={pos}"
+            );
+        }
+        assert_eq!(validate_simd(&full[..len], 0), Ok(()));
+    }
+}
+
+// ── Unchecked decode on invalid input ──────────────────────────────────────
+
+#[test]
+fn test_decode_validated_simd_on_invalid_input_does_not_panic() {
+    // `decode_to_slice_unchecked` relies on the kernels being safe for any
+    // bytes; the result is unspecified but every read/write stays in bounds.
+    let junk: Vec<u8> = (0..400u32)
+        .map(|i| u8::try_from(i * 97 % 256).unwrap())
+        .collect();
+    for len in (0..=junk.len()).step_by(2) {
+        let mut dst = std::vec![0u8; len / 2];
+        decode_validated_simd(&junk[..len], &mut dst);
+    }
+}
+
+// ── Streaming stores ───────────────────────────────────────────────────────
+
+#[cfg(all(target_arch = "x86_64", target_feature = "sse2"))]
+#[test]
+fn test_stream_decode_every_alignment_matches_scalar() {
+    let src: Vec<u8> = (0..300u32)
+        .map(|i| u8::try_from(i * 7 % 256).unwrap())
+        .collect();
+    let mut hex = std::vec![0u8; src.len() * 2];
+    encode_scalar(&src, &mut hex, false);
+    // Shift the output start through every offset mod 16 so the aligned
+    // head, streamed body and tail all vary.
+    let mut backing = std::vec![0u8; src.len() + 32];
+    for shift in 0..16 {
+        for len in [0, 1, 15, 16, 17, 31, 32, 33, 100, 300] {
+            backing.fill(0xAA);
+            let dst = &mut backing[shift..shift + len];
+            decode_validated_stream(&hex[..len * 2], dst);
+            assert_eq!(dst, &src[..len], "shift={shift} len={len}");
+            assert!(backing[shift + len..].iter().all(|&b| b == 0xAA));
+        }
+    }
+}
+
+#[cfg(all(target_arch = "x86_64", target_feature = "sse2"))]
+#[test]
+fn test_stream_encode_every_alignment_matches_scalar() {
+    let src: Vec<u8> = (0..300u32)
+        .map(|i| u8::try_from(i * 13 % 256).unwrap())
+        .collect();
+    // Odd shifts take the regular-store fallback.
+    let mut backing = std::vec![0u8; src.len() * 2 + 32];
+    for shift in 0..16 {
+        for len in [0, 1, 7, 8, 9, 16, 17, 40, 300] {
+            for lowercase in [true, false] {
+                let mut expected = std::vec![0u8; len * 2];
+                encode_scalar(&src[..len], &mut expected, lowercase);
+                backing.fill(0xAA);
+                let dst = &mut backing[shift..shift + len * 2];
+                encode_stream(&src[..len], dst, lowercase);
+                assert_eq!(dst, &expected[..], "shift={shift} len={len}");
+                assert!(backing[shift + len * 2..].iter().all(|&b| b == 0xAA));
+            }
+        }
+    }
+}
+
+#[test]
+fn test_stream_threshold_public_api_matches_regular_path() {
+    let src: Vec<u8> = (0..1000u32)
+        .map(|i| u8::try_from(i * 31 % 256).unwrap())
+        .collect();
+    let mut regular_hex = std::vec![0u8; 2000];
+    crate::encode_to_slice(&src, &mut regular_hex, true).unwrap();
+
+    // The only test that sets the threshold; results never depend on it.
+    crate::set_stream_threshold(Some(0));
+    let mut hex = std::vec![0u8; 2000];
+    crate::encode_to_slice(&src, &mut hex, true).unwrap();
+    let mut out = std::vec![0u8; 1000];
+    let res = crate::decode_to_slice(&hex, &mut out);
+    let mut bad = hex.clone();
+    bad[1234] = b'g';
+    let bad_res = crate::decode_to_slice(&bad, &mut std::vec![0u8; 1000]);
+    crate::set_stream_threshold(None);
+
+    assert_eq!(hex, regular_hex);
+    assert_eq!(res, Ok(1000));
+    assert_eq!(out, src);
+    assert_eq!(
+        bad_res,
+        Err(Error::InvalidByte {
+            index: 1234,
+            byte: b'g'
+        })
+    );
+}
+
+// ── Byte-separated encode ──────────────────────────────────────────────────
+
+/// Reference layout for one chunk run: `sep` after every `group` bytes.
+fn group_sep_reference(src: &[u8], sep: u8, group: usize, lowercase: bool) -> Vec<u8> {
+    let mut out = Vec::new();
+    let mut hex = [0u8; 2];
+    for g in src.chunks(group) {
+        for b in g {
+            encode_scalar(core::slice::from_ref(b), &mut hex, lowercase);
+            out.extend_from_slice(&hex);
+        }
+        out.push(sep);
+    }
+    out
+}
+
+#[cfg(all(target_arch = "x86_64", target_feature = "sse2", feature = "std"))]
+#[test]
+fn test_group_sep_kernels_match_scalar() {
+    // Called directly: the dispatcher picks SSSE3 from the AVX2 tier up and
+    // SSE2 only on the base tier.
+    let ssse3 = std::is_x86_feature_detected!("ssse3");
+    let src: Vec<u8> = (0u8..=255).collect();
+    for group in [1, 2, 4, 8] {
+        let tables = sep::spread_tables(group).unwrap();
+        let out_len = 32 + 16 / group;
+        for iters in 0..=src.len() / 16 {
+            for lowercase in [true, false] {
+                let want = group_sep_reference(&src[..iters * 16], b'-', group, lowercase);
+
+                let mut out = std::vec![0u8; iters * out_len];
+                // SAFETY: SSE2 is baseline on x86_64.
+                unsafe {
+                    match group {
+                        1 => {
+                            sep::encode_group_sep_sse2::<1>(&src, &mut out, b'-', lowercase, iters)
+                        }
+                        2 => {
+                            sep::encode_group_sep_sse2::<2>(&src, &mut out, b'-', lowercase, iters)
+                        }
+                        4 => {
+                            sep::encode_group_sep_sse2::<4>(&src, &mut out, b'-', lowercase, iters)
+                        }
+                        _ => {
+                            sep::encode_group_sep_sse2::<8>(&src, &mut out, b'-', lowercase, iters)
+                        }
+                    }
+                }
+                assert_eq!(out, want, "sse2 group={group} iters={iters}");
+
+                if ssse3 {
+                    let mut out = std::vec![0u8; iters * out_len];
+                    // SAFETY: SSSE3 was detected above.
+                    unsafe {
+                        sep::encode_group_sep_ssse3(&src, &mut out, b'-', tables, lowercase, iters);
+                    }
+                    assert_eq!(out, want, "ssse3 group={group} iters={iters}");
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_group_sep_dispatch_matches_scalar() {
+    let src: Vec<u8> = (0u8..=255).rev().collect();
+    for group in [1, 2, 3, 4, 8, 16] {
+        let mut out = std::vec![0u8; src.len() * 3];
+        let done = encode_group_sep_simd(&src, &mut out, b' ', group, true);
+        assert_eq!(done % 16, 0);
+        assert!(done < src.len());
+        let want = group_sep_reference(&src[..done], b' ', group, true);
+        assert_eq!(&out[..want.len()], &want[..], "group={group}");
+    }
+}
+
+// ── Case-strict validation: every tier agrees with the scalar check ────────
+
+#[test]
+fn test_case_validators_match_scalar() {
+    let tiers = |hex: &[u8], case: CasePolicy| -> Vec<Result<(), Error>> {
+        let mut out = std::vec![
+            validate_base(hex, 3, case),
+            validate_case_simd(hex, 3, case)
+        ];
+        #[cfg(all(target_arch = "x86_64", target_feature = "sse2"))]
+        {
+            // SAFETY: each tier is only run after its features are detected.
+            if std::is_x86_feature_detected!("avx2") {
+                out.push(unsafe { avx2::validate_avx2(hex, 3, case) });
+            }
+            if has_avx512_tier() {
+                out.push(unsafe { avx512::validate_avx512(hex, 3, case) });
+            }
+        }
+        out
+    };
+
+    for mixed in tier_vectors().into_iter().step_by(3) {
+        for case in [CasePolicy::Any, CasePolicy::Lower, CasePolicy::Upper] {
+            let hex = match case {
+                CasePolicy::Any => mixed.clone(),
+                CasePolicy::Lower => mixed.to_ascii_lowercase(),
+                CasePolicy::Upper => mixed.to_ascii_uppercase(),
+            };
+            for res in tiers(&hex, case) {
+                assert_eq!(res, Ok(()));
+            }
+            // The mixed input fails the strict cases at its first letter of
+            // the other case.
+            let expected = validate_case_scalar(&mixed, 3, case);
+            for res in tiers(&mixed, case) {
+                assert_eq!(res, expected, "len={} {case:?}", mixed.len());
+            }
+            // Every byte value at every position.
+            for pos in (0..hex.len()).step_by(11) {
+                for b in 0..=255u8 {
+                    let mut bad = hex.clone();
+                    bad[pos] = b;
+                    let expected = validate_case_scalar(&bad, 3, case);
+                    for res in tiers(&bad, case) {
+                        assert_eq!(res, expected, "len={} pos={pos} byte={b:#04x}", hex.len());
+                    }
+                }
+            }
+        }
+    }
+}
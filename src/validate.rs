@@ -0,0 +1,427 @@
+//! Validation and classification of ASCII hex without decoding.
+
+use crate::Error;
+
+/// Case of the hex letters (`a-f` / `A-F`) found in an input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CaseClass {
+    /// No letters at all: only `0-9` (or empty input).
+    DigitsOnly,
+    /// Letters are all lowercase (`a-f`).
+    Lower,
+    /// Letters are all uppercase (`A-F`).
+    Upper,
+    /// Both lowercase and uppercase letters are present.
+    Mixed,
+}
+
+/// Which letter case a case-strict decoder accepts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CasePolicy {
+    /// `a-f` and `A-F`, as the regular decoders.
+    Any,
+    /// Only `a-f` (canonical lowercase).
+    Lower,
+    /// Only `A-F` (RFC 4648 base16).
+    Upper,
+}
+
+/// Report produced by [`classify`].
+///
+/// A leading `0x` / `0X` is detected and reported via `has_prefix`; every
+/// other field then describes the digits after it. Indices are always into
+/// the original input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Classification {
+    /// Total input length in bytes, including any prefix.
+    pub len: usize,
+    /// `true` if the number of digits (after any prefix) is odd.
+    pub odd_length: bool,
+    /// Case of the hex letters among the digits. Invalid bytes are ignored.
+    pub case: CaseClass,
+    /// `true` if the input starts with `0x` or `0X`.
+    pub has_prefix: bool,
+    /// Index of the first non-hex byte after any prefix, if any.
+    pub first_invalid: Option<usize>,
+}
+
+impl Classification {
+    /// `true` if the digits are even-length and contain only hex characters,
+    /// i.e. they would decode without error once any prefix is removed.
+    pub fn is_valid(&self) -> bool {
+        !self.odd_length && self.first_invalid.is_none()
+    }
+}
+
+/// Check that `src_hex` is decodable hex without writing anything.
+///
+/// Accepts exactly what [`decode_to_slice`](crate::decode_to_slice) accepts:
+/// an even number of `0-9`, `a-f`, `A-F` bytes. Uses the SIMD validators
+/// under the `simd` feature.
+///
+/// # Examples
+/// ```
+/// use fast_hex_lite::{validate, Error};
+///
+/// assert!(validate(b"deadBEEF").is_ok());
+/// assert_eq!(validate(b"abc"), Err(Error::OddLength));
+/// assert_eq!(validate(b"de:d"), Err(Error::InvalidByte { index: 2, byte: b':' }));
+/// ```
+#[inline]
+pub fn validate(src_hex: &[u8]) -> Result<(), Error> {
+    if !src_hex.len().is_multiple_of(2) {
+        return Err(Error::OddLength);
+    }
+    validate_digits(src_hex, 0)
+}
+
+/// Check that `src_hex` is hex in the letter case required by `case`,
+/// without writing anything.
+///
+/// Accepts exactly what [`decode_to_slice_case`](crate::decode_to_slice_case)
+/// accepts. Uses the SIMD validators under the `simd` feature.
+///
+/// # Errors
+///
+/// - [`Error::OddLength`] if the length is odd.
+/// - [`Error::WrongCase`] for a hex letter of the other case.
+/// - [`Error::InvalidByte`] for a non-hex byte.
+///
+/// Whichever of the last two comes first in the input is reported.
+///
+/// # Examples
+/// ```
+/// use fast_hex_lite::{validate_case, CasePolicy, Error};
+///
+/// assert!(validate_case(b"deadbeef", CasePolicy::Lower).is_ok());
+/// assert_eq!(
+///     validate_case(b"deadBEEF", CasePolicy::Lower),
+///     Err(Error::WrongCase { index: 4, byte: b'B' })
+/// );
+/// ```
+#[inline]
+pub fn validate_case(src_hex: &[u8], case: CasePolicy) -> Result<(), Error> {
+    if !src_hex.len().is_multiple_of(2) {
+        return Err(Error::OddLength);
+    }
+    validate_digits_case(src_hex, 0, case)
+}
+
+/// Classify `src` in one read-only pass: length, odd-length, letter case,
+/// `0x` prefix and the first invalid byte.
+///
+/// Unlike [`validate`], this never fails; it reports everything it finds.
+///
+/// # Examples
+/// ```
+/// use fast_hex_lite::{classify, CaseClass};
+///
+/// let c = classify(b"0xDeadBeef");
+/// assert!(c.has_prefix);
+/// assert_eq!(c.case, CaseClass::Mixed);
+/// assert!(c.is_valid());
+///
+/// let c = classify(b"12z45");
+/// assert!(c.odd_length);
+/// assert_eq!(c.case, CaseClass::DigitsOnly);
+/// assert_eq!(c.first_invalid, Some(2));
+/// ```
+pub fn classify(src: &[u8]) -> Classification {
+    let has_prefix = crate::prefix::has_prefix(src);
+    let offset = if has_prefix { 2 } else { 0 };
+    let digits = &src[offset..];
+
+    let first_invalid = match validate_digits(digits, offset) {
+        Ok(()) => None,
+        Err(Error::InvalidByte { index, .. }) => Some(index),
+        Err(_) => unreachable!("digit validation only reports InvalidByte"),
+    };
+
+    let case = match letter_case(digits) {
+        0 => CaseClass::DigitsOnly,
+        HAS_LOWER => CaseClass::Lower,
+        HAS_UPPER => CaseClass::Upper,
+        _ => CaseClass::Mixed,
+    };
+
+    Classification {
+        len: src.len(),
+        odd_length: !digits.len().is_multiple_of(2),
+        case,
+        has_prefix,
+        first_invalid,
+    }
+}
+
+// ── Shared helpers ─────────────────────────────────────────────────────────
+
+/// Letter-case flags: at least one `a-f` seen.
+pub(crate) const HAS_LOWER: u8 = 1;
+/// Letter-case flags: at least one `A-F` seen.
+pub(crate) const HAS_UPPER: u8 = 2;
+
+#[inline]
+pub(crate) fn validate_digits(src_hex: &[u8], hex_base: usize) -> Result<(), Error> {
+    #[cfg(all(feature = "simd", not(feature = "forbid-unsafe")))]
+    {
+        crate::simd::validate_simd(src_hex, hex_base)
+    }
+    #[cfg(any(not(feature = "simd"), feature = "forbid-unsafe"))]
+    {
+        validate_hex_scalar(src_hex, hex_base)
+    }
+}
+
+/// Like [`validate_digits`], but only letters of `case` are accepted; a
+/// letter of the other case is reported as [`Error::WrongCase`].
+#[inline]
+pub(crate) fn validate_digits_case(
+    src_hex: &[u8],
+    hex_base: usize,
+    case: CasePolicy,
+) -> Result<(), Error> {
+    #[cfg(all(feature = "simd", not(feature = "forbid-unsafe")))]
+    let res = crate::simd::validate_case_simd(src_hex, hex_base, case);
+    #[cfg(any(not(feature = "simd"), feature = "forbid-unsafe"))]
+    let res = validate_case_scalar(src_hex, hex_base, case);
+
+    // The kernels only know "outside the accepted set"; tell the two apart
+    // on the error path.
+    res.map_err(|e| match e {
+        Error::InvalidByte { index, byte } if byte.is_ascii_hexdigit() => {
+            Error::WrongCase { index, byte }
+        }
+        e => e,
+    })
+}
+
+/// Accepted letters for `case` as `(fold, first)`: a byte `b` is an accepted
+/// letter if `b | fold` is in `first..=first + 5`. `Any` folds to lowercase.
+#[inline]
+pub(crate) const fn letter_range(case: CasePolicy) -> (u8, u8) {
+    match case {
+        CasePolicy::Any => (0x20, b'a'),
+        CasePolicy::Lower => (0, b'a'),
+        CasePolicy::Upper => (0, b'A'),
+    }
+}
+
+#[inline]
+fn letter_case(src: &[u8]) -> u8 {
+    #[cfg(all(feature = "simd", not(feature = "forbid-unsafe")))]
+    {
+        crate::simd::letter_case_simd(src)
+    }
+    #[cfg(any(not(feature = "simd"), feature = "forbid-unsafe"))]
+    {
+        letter_case_scalar(src)
+    }
+}
+
+/// Scalar validation: first non-hex byte, offset by `hex_base`.
+pub(crate) fn validate_hex_scalar(src_hex: &[u8], hex_base: usize) -> Result<(), Error> {
+    for (i, &b) in src_hex.iter().enumerate() {
+        if !b.is_ascii_hexdigit() {
+            return Err(Error::InvalidByte {
+                index: hex_base + i,
+                byte: b,
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Scalar case-strict validation: first byte that is not a digit or an
+/// accepted letter, reported as `InvalidByte` and offset by `hex_base`.
+pub(crate) fn validate_case_scalar(
+    src_hex: &[u8],
+    hex_base: usize,
+    case: CasePolicy,
+) -> Result<(), Error> {
+    let (fold, first) = letter_range(case);
+    for (i, &b) in src_hex.iter().enumerate() {
+        if !b.is_ascii_digit() && (b | fold).wrapping_sub(first) >= 6 {
+            return Err(Error::InvalidByte {
+                index: hex_base + i,
+                byte: b,
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Scalar letter-case scan, returning `HAS_LOWER` / `HAS_UPPER` flags.
+pub(crate) fn letter_case_scalar(src: &[u8]) -> u8 {
+    let mut flags = 0;
+    for &b in src {
+        match b {
+            b'a'..=b'f' => flags |= HAS_LOWER,
+            b'A'..=b'F' => flags |= HAS_UPPER,
+            _ => {}
+        }
+        if flags == HAS_LOWER | HAS_UPPER {
+            break;
+        }
+    }
+    flags
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+    use super::*;
+    use std::prelude::v1::*;
+
+    // ── validate ───────────────────────────────────────────────────────────
+
+    #[test]
+    fn test_validate_empty() {
+        assert_eq!(validate(b""), Ok(()));
+    }
+
+    #[test]
+    fn test_validate_accepts_all_cases() {
+        assert_eq!(validate(b"0123456789abcdefABCDEF"), Ok(()));
+    }
+
+    #[test]
+    fn test_validate_odd_length() {
+        assert_eq!(validate(b"abc"), Err(Error::OddLength));
+    }
+
+    #[test]
+    fn test_validate_odd_length_wins_over_invalid_byte() {
+        // Same precedence as `decode_to_slice`.
+        assert_eq!(validate(b"zzz"), Err(Error::OddLength));
+    }
+
+    #[test]
+    fn test_validate_rejects_prefix() {
+        assert_eq!(
+            validate(b"0xff"),
+            Err(Error::InvalidByte {
+                index: 1,
+                byte: b'x'
+            })
+        );
+    }
+
+    #[test]
+    fn test_validate_matches_decode_at_every_position() {
+        let src: Vec<u8> = (0u8..=255).collect();
+        let mut hex = std::vec![0u8; 512];
+        crate::encode_to_slice(&src, &mut hex, true).unwrap();
+        for pos in (0..hex.len()).step_by(7) {
+            let mut bad = hex.clone();
+            bad[pos] = b'g';
+            let mut dst = [0u8; 256];
+            assert_eq!(
+                validate(&bad),
+                crate::decode_to_slice(&bad, &mut dst).map(|_| ())
+            );
+        }
+    }
+
+    // ── classify ───────────────────────────────────────────────────────────
+
+    #[test]
+    fn test_classify_empty() {
+        assert_eq!(
+            classify(b""),
+            Classification {
+                len: 0,
+                odd_length: false,
+                case: CaseClass::DigitsOnly,
+                has_prefix: false,
+                first_invalid: None,
+            }
+        );
+    }
+
+    #[test]
+    fn test_classify_case_classes() {
+        assert_eq!(classify(b"0123").case, CaseClass::DigitsOnly);
+        assert_eq!(classify(b"00ff").case, CaseClass::Lower);
+        assert_eq!(classify(b"00FF").case, CaseClass::Upper);
+        assert_eq!(classify(b"00fF").case, CaseClass::Mixed);
+    }
+
+    #[test]
+    fn test_classify_case_in_long_input() {
+        // Letters of the "other" case only appear past the first SIMD chunks.
+        let mut hex = std::vec![b'a'; 200];
+        assert_eq!(classify(&hex).case, CaseClass::Lower);
+        hex[197] = b'E';
+        assert_eq!(classify(&hex).case, CaseClass::Mixed);
+        let hex = std::vec![b'7'; 199];
+        assert_eq!(classify(&hex).case, CaseClass::DigitsOnly);
+    }
+
+    #[test]
+    fn test_classify_ignores_non_hex_letters() {
+        // 'g'/'G' and 'x' are not hex letters and do not affect the case class.
+        let c = classify(b"abGg");
+        assert_eq!(c.case, CaseClass::Lower);
+        assert_eq!(c.first_invalid, Some(2));
+    }
+
+    #[test]
+    fn test_classify_prefix() {
+        let c = classify(b"0xABCD");
+        assert!(c.has_prefix);
+        assert_eq!(c.len, 6);
+        assert_eq!(c.case, CaseClass::Upper);
+        assert!(!c.odd_length);
+        assert!(c.is_valid());
+
+        let c = classify(b"0X1");
+        assert!(c.has_prefix);
+        assert!(c.odd_length);
+        assert!(!c.is_valid());
+
+        let c = classify(b"0x");
+        assert!(c.has_prefix);
+        assert!(c.is_valid());
+    }
+
+    #[test]
+    fn test_classify_invalid_index_is_into_original_input() {
+        assert_eq!(classify(b"0x12z4").first_invalid, Some(4));
+        assert_eq!(classify(b"12z4").first_invalid, Some(2));
+    }
+
+    #[test]
+    fn test_classify_lone_zero_is_not_a_prefix() {
+        let c = classify(b"0");
+        assert!(!c.has_prefix);
+        assert!(c.odd_length);
+        assert_eq!(c.first_invalid, None);
+    }
+
+    #[test]
+    fn test_classify_matches_scalar_reference() {
+        let inputs: [&[u8]; 6] = [
+            b"deadbeefDEADBEEF0123456789abcdef0123",
+            b"0123456789012345678901234567890123456789",
+            b"ABCDEFABCDEFABCDEFABCDEFABCDEF",
+            b"ffffffffffffffffffffffffffffffff ",
+            b"\xff\x00abcdef",
+            b"0x0123456789abcdef0123456789abcdeF",
+        ];
+        for src in inputs {
+            let c = classify(src);
+            let digits = if c.has_prefix { &src[2..] } else { src };
+            assert_eq!(letter_case(digits), letter_case_scalar(digits), "{src:?}");
+            assert_eq!(
+                c.first_invalid,
+                validate_hex_scalar(digits, usize::from(c.has_prefix) * 2)
+                    .err()
+                    .map(|e| match e {
+                        Error::InvalidByte { index, .. } => index,
+                        _ => unreachable!(),
+                    }),
+                "{src:?}"
+            );
+        }
+    }
+}
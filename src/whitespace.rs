@@ -0,0 +1,356 @@
+//! Whitespace-insensitive decoding (hex wrapped across lines, indented).
+
+use crate::decode::decode_validated;
+use crate::validate::validate_digits;
+use crate::Error;
+
+/// Hex digits gathered per stack block when decoding in place.
+const GATHER_HEX: usize = 256;
+
+/// Space, tab, CR and LF.
+#[inline]
+fn is_ws(b: u8) -> bool {
+    matches!(b, b' ' | b'\t' | b'\r' | b'\n')
+}
+
+/// Returns the number of bytes `src_hex` decodes to once whitespace is
+/// skipped.
+///
+/// This is a full check of the input, so it fails exactly when
+/// [`decode_to_slice_ws`] would (apart from the output size).
+///
+/// # Errors
+///
+/// See [`decode_to_slice_ws`].
+///
+/// # Examples
+/// ```
+/// use fast_hex_lite::decoded_len_ws;
+///
+/// assert_eq!(decoded_len_ws(b"dead\n  beef\n", false), Ok(4));
+/// ```
+pub fn decoded_len_ws(src_hex: &[u8], split_bytes: bool) -> Result<usize, Error> {
+    scan(src_hex, split_bytes)
+}
+
+/// Decode hex that may contain ASCII whitespace (space, tab, CR, LF), such as
+/// test vectors wrapped across lines or indented in a config file.
+///
+/// Any amount of whitespace may appear before, after and between bytes. With
+/// `split_bytes`, it may also appear between the two digits of a byte
+/// (`"d e a d"`). Like [`decode_to_slice`](crate::decode_to_slice), the whole
+/// input is checked before anything is written, so `dst` is unchanged on
+/// error.
+///
+/// Returns the number of bytes written.
+///
+/// # Errors
+///
+/// - [`Error::InvalidByteAt`] for the first byte that is neither hex nor
+///   whitespace, or is whitespace inside a byte without `split_bytes`. It
+///   carries the line and column of the byte as well as its index.
+/// - [`Error::OddLength`] if the number of hex digits is odd.
+/// - [`Error::OutputTooSmall`] if `dst` is too small.
+///
+/// # Examples
+/// ```
+/// use fast_hex_lite::{decode_to_slice_ws, Error};
+///
+/// let mut out = [0u8; 4];
+/// decode_to_slice_ws(b"dead\n    beef\n", &mut out, false).unwrap();
+/// assert_eq!(out, [0xde, 0xad, 0xbe, 0xef]);
+///
+/// let err = decode_to_slice_ws(b"dead\nbeeg\n", &mut out, false).unwrap_err();
+/// assert_eq!(err, Error::InvalidByteAt { index: 8, byte: b'g', line: 2, column: 4 });
+/// ```
+pub fn decode_to_slice_ws(
+    src_hex: &[u8],
+    dst: &mut [u8],
+    split_bytes: bool,
+) -> Result<usize, Error> {
+    // --- PASS 1: validate (no writes) ---
+    let out_len = scan(src_hex, split_bytes)?;
+    if dst.len() < out_len {
+        return Err(Error::OutputTooSmall);
+    }
+
+    // --- PASS 2: decode each run of digits straight into `dst` ---
+    // A run only has an odd length when a byte is split by whitespace; its
+    // last digit is carried over to the next non-empty run.
+    let mut out_off = 0;
+    let mut carry = None;
+    for mut run in src_hex.split(|&b| is_ws(b)) {
+        if run.is_empty() {
+            continue;
+        }
+        if let Some(hi) = carry.take() {
+            decode_validated(&[hi, run[0]], &mut dst[out_off..=out_off]);
+            out_off += 1;
+            run = &run[1..];
+        }
+        let even = run.len() & !1;
+        decode_validated(&run[..even], &mut dst[out_off..out_off + even / 2]);
+        out_off += even / 2;
+        if even != run.len() {
+            carry = Some(run[even]);
+        }
+    }
+    debug_assert!(carry.is_none() && out_off == out_len);
+
+    Ok(out_len)
+}
+
+/// Decode whitespace-containing hex in place, compacting the digits while
+/// decoding.
+///
+/// Same input rules as [`decode_to_slice_ws`]. The decoded bytes are written
+/// to the start of `buf`; the rest of `buf` is left in an unspecified state.
+/// The input is validated first, so on error `buf` is unchanged.
+///
+/// Returns the number of bytes written.
+///
+/// # Errors
+///
+/// Same as [`decode_to_slice_ws`], without [`Error::OutputTooSmall`].
+///
+/// # Examples
+/// ```
+/// use fast_hex_lite::decode_in_place_ws;
+///
+/// let mut buf = *b"de ad\r\n be ef";
+/// let n = decode_in_place_ws(&mut buf, false).unwrap();
+/// assert_eq!(&buf[..n], &[0xde, 0xad, 0xbe, 0xef]);
+/// ```
+pub fn decode_in_place_ws(buf: &mut [u8], split_bytes: bool) -> Result<usize, Error> {
+    // --- PASS 1: validate (no writes) ---
+    let out_len = scan(buf, split_bytes)?;
+
+    // --- PASS 2: gather digits into a stack block, decode it to the front ---
+    // After `d` digits have been read, at most `d / 2` bytes have been
+    // written, so writes never reach input that has not been read yet.
+    let mut block = [0u8; GATHER_HEX];
+    let mut len = 0;
+    let mut out_off = 0;
+    let mut pos = 0;
+    while pos < buf.len() {
+        let end = buf[pos..]
+            .iter()
+            .position(|&b| is_ws(b))
+            .map_or(buf.len(), |k| pos + k);
+        while pos < end {
+            let take = (GATHER_HEX - len).min(end - pos);
+            block[len..len + take].copy_from_slice(&buf[pos..pos + take]);
+            len += take;
+            pos += take;
+            if len == GATHER_HEX {
+                decode_validated(&block, &mut buf[out_off..out_off + GATHER_HEX / 2]);
+                out_off += GATHER_HEX / 2;
+                len = 0;
+            }
+        }
+        pos = end + 1;
+    }
+    decode_validated(&block[..len], &mut buf[out_off..out_off + len / 2]);
+    debug_assert_eq!(out_off + len / 2, out_len);
+
+    Ok(out_len)
+}
+
+/// Pass 1: check digits and whitespace placement, return the decoded length.
+///
+/// Each digit run between whitespace is checked in bulk (SIMD under `simd`),
+/// so the validator never looks past the end of the run.
+fn scan(src: &[u8], split_bytes: bool) -> Result<usize, Error> {
+    let mut digits = 0;
+    let mut pos = 0;
+    // Whitespace after an odd digit count; an error only if a digit follows
+    // (otherwise the input just has an odd number of digits).
+    let mut split_at = None;
+    while pos < src.len() {
+        let end = src[pos..]
+            .iter()
+            .position(|&b| is_ws(b))
+            .map_or(src.len(), |k| pos + k);
+        if end > pos {
+            if let Some(at) = split_at {
+                return Err(located(src, at));
+            }
+            validate_digits(&src[pos..end], pos).map_err(|e| match e {
+                Error::InvalidByte { index, .. } => located(src, index),
+                e => e,
+            })?;
+            digits += end - pos;
+        }
+        if end == src.len() {
+            break;
+        }
+        if !split_bytes && !digits.is_multiple_of(2) && split_at.is_none() {
+            split_at = Some(end);
+        }
+        pos = end + 1;
+    }
+    crate::decoded_len(digits)
+}
+
+/// [`Error::InvalidByteAt`] for `src[index]`, with its 1-based line and
+/// column. Only computed on the error path.
+fn located(src: &[u8], index: usize) -> Error {
+    let before = &src[..index];
+    let line_start = before
+        .iter()
+        .rposition(|&b| b == b'\n')
+        .map_or(0, |k| k + 1);
+    Error::InvalidByteAt {
+        index,
+        byte: src[index],
+        line: before.split(|&b| b == b'\n').count(),
+        column: 1 + index - line_start,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+    use super::*;
+    use std::prelude::v1::*;
+
+    fn hex(src: &[u8]) -> Vec<u8> {
+        src.iter()
+            .flat_map(|b| std::format!("{b:02x}").into_bytes())
+            .collect()
+    }
+
+    /// Insert `ws` before every `every`-th digit, starting at `phase`.
+    fn wrap(hex: &[u8], ws: &[u8], every: usize, phase: usize) -> Vec<u8> {
+        let mut out = Vec::new();
+        for (i, &b) in hex.iter().enumerate() {
+            if (i + phase).is_multiple_of(every) {
+                out.extend_from_slice(ws);
+            }
+            out.push(b);
+        }
+        out.extend_from_slice(ws);
+        out
+    }
+
+    fn decode_both(src: &[u8], split_bytes: bool) -> Result<Vec<u8>, Error> {
+        let mut dst = vec![0u8; src.len() / 2];
+        let n = decode_to_slice_ws(src, &mut dst, split_bytes)?;
+        dst.truncate(n);
+
+        let mut buf = src.to_vec();
+        let m = decode_in_place_ws(&mut buf, split_bytes).unwrap();
+        assert_eq!(&buf[..m], &dst[..]);
+        assert_eq!(decoded_len_ws(src, split_bytes), Ok(n));
+        Ok(dst)
+    }
+
+    #[test]
+    fn test_ws_roundtrip_layouts() {
+        let src: Vec<u8> = (0..700u32)
+            .map(|i| u8::try_from(i * 41 % 256).unwrap())
+            .collect();
+        for len in [0, 1, 2, 15, 16, 17, 63, 64, 65, 128, 129, 300, 700] {
+            let h = hex(&src[..len]);
+            for ws in [&b" "[..], b"\n", b"\r\n", b"\n\t\t", b"    "] {
+                for every in [2, 4, 32, 64, 200] {
+                    for phase in [0, 2] {
+                        let text = wrap(&h, ws, every, phase);
+                        assert_eq!(decode_both(&text, false).unwrap(), &src[..len]);
+                        assert_eq!(decode_both(&text, true).unwrap(), &src[..len]);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_ws_split_bytes() {
+        let src: Vec<u8> = (0..300u32)
+            .map(|i| u8::try_from(i * 7 % 256).unwrap())
+            .collect();
+        let h = hex(&src);
+        for every in [1, 3, 5, 33, 257] {
+            for phase in [0, 1] {
+                let text = wrap(&h, b" \n", every, phase);
+                assert_eq!(decode_both(&text, true).unwrap(), src);
+            }
+        }
+        assert_eq!(decode_both(b"d e\na d", true).unwrap(), [0xde, 0xad]);
+        assert_eq!(
+            decode_both(b"d e\na d", false),
+            Err(Error::InvalidByteAt {
+                index: 1,
+                byte: b' ',
+                line: 1,
+                column: 2
+            })
+        );
+    }
+
+    #[test]
+    fn test_ws_errors_report_line_and_column() {
+        let text = b"deadbeef\r\n  0011\n\t22zz\n";
+        let mut dst = [0xAAu8; 16];
+        let err = decode_to_slice_ws(text, &mut dst, true).unwrap_err();
+        assert_eq!(
+            err,
+            Error::InvalidByteAt {
+                index: 20,
+                byte: b'z',
+                line: 3,
+                column: 4
+            }
+        );
+        assert_eq!(dst, [0xAA; 16]);
+
+        let mut buf = text.to_vec();
+        assert_eq!(decode_in_place_ws(&mut buf, true), Err(err));
+        assert_eq!(buf, text);
+
+        // Invalid byte past a long valid run (found by the bulk check).
+        let mut long = wrap(&hex(&[0x5A; 100]), b"\n", 64, 0);
+        long.pop();
+        long.push(b'x');
+        let index = long.len() - 1;
+        assert_eq!(
+            decoded_len_ws(&long, false),
+            Err(Error::InvalidByteAt {
+                index,
+                byte: b'x',
+                line: 5,
+                column: 9
+            })
+        );
+    }
+
+    #[test]
+    fn test_ws_length_errors() {
+        assert_eq!(decoded_len_ws(b"", false), Ok(0));
+        assert_eq!(decoded_len_ws(b" \n\t\r ", false), Ok(0));
+        assert_eq!(decoded_len_ws(b"abc\n", false), Err(Error::OddLength));
+        assert_eq!(decoded_len_ws(b"a b c", true), Err(Error::OddLength));
+
+        let mut dst = [0u8; 1];
+        assert_eq!(
+            decode_to_slice_ws(b"de\nad", &mut dst, false),
+            Err(Error::OutputTooSmall)
+        );
+    }
+
+    #[test]
+    fn test_ws_other_bytes_rejected() {
+        for b in [b'\x0b', b'\x0c', b':', 0x00, 0xA0] {
+            let text = [b'd', b'e', b, b'a', b'd'];
+            assert_eq!(
+                decoded_len_ws(&text, true),
+                Err(Error::InvalidByteAt {
+                    index: 2,
+                    byte: b,
+                    line: 1,
+                    column: 3
+                })
+            );
+        }
+    }
+}